@@ -129,6 +129,11 @@ fn make_sys_code(central_items: &CentralItems) -> String {
         ..
     } = central_items;
 
+    let variant_ty_enumerators_name_str: Vec<String> = variant_ty_enumerators_pascal
+        .iter()
+        .map(|pascal_ident| to_snake_case(&pascal_ident.to_string()))
+        .collect();
+
     let sys_tokens = quote! {
         use crate::{GDExtensionVariantPtr, GDExtensionTypePtr, GDExtensionConstTypePtr, GodotFfi, ffi_methods};
 
@@ -157,6 +162,30 @@ fn make_sys_code(central_items: &CentralItems) -> String {
             )*
         }
 
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+        #[repr(i32)]
+        pub enum VariantOperator {
+            #(
+                #variant_op_enumerators_pascal = #variant_op_enumerators_ord,
+            )*
+        }
+
+        #[derive(Copy, Clone, Debug)]
+        pub struct BuiltinTypeMeta {
+            pub variant_type: VariantType,
+            pub snake_case_name: &'static str,
+        }
+
+        pub static BUILTIN_TYPE_INFO: &[BuiltinTypeMeta] = &[
+            BuiltinTypeMeta { variant_type: VariantType::Nil, snake_case_name: "nil" },
+            #(
+                BuiltinTypeMeta {
+                    variant_type: VariantType::#variant_ty_enumerators_pascal,
+                    snake_case_name: #variant_ty_enumerators_name_str,
+                },
+            )*
+        ];
+
         impl VariantType {
             #[doc(hidden)]
             pub fn from_sys(enumerator: crate::GDExtensionVariantType) -> Self {
@@ -165,4 +194,26 @@ fn make_sys_code(central_items: &CentralItems) -> String {
                     0 => Self::Nil,
                     #(
                         #variant_ty_enumerators_ord => Self::#variant_ty_enumerators_pascal,
-            
\ No newline at end of file
+                    )*
+                    _ => unreachable!("invalid variant type {}", enumerator),
+                }
+            }
+
+            /// Looks up this variant type's entry in [`BUILTIN_TYPE_INFO`], which carries the
+            /// Godot-facing name generated alongside it.
+            ///
+            /// Only the snake_case name is available here: `has_destructor` and the constructor/
+            /// operator lists gathered per-type while building `CentralItems` aren't threaded
+            /// through to this table, since nothing outside method-table generation consumes them
+            /// today.
+            pub fn metadata(self) -> &'static BuiltinTypeMeta {
+                BUILTIN_TYPE_INFO
+                    .iter()
+                    .find(|meta| meta.variant_type == self)
+                    .expect("VariantType::metadata(): no BUILTIN_TYPE_INFO entry for this variant")
+            }
+        }
+    };
+
+    sys_tokens.to_string()
+}
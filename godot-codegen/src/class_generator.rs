@@ -19,6 +19,16 @@ use crate::{
     GeneratedClassModule, ModName, RustTy, TyName,
 };
 
+#[cfg(feature = "codegen-rustdoc")]
+use crate::doc_gen::make_doc_attr;
+
+/// No-op stand-in for [`doc_gen::make_doc_attr`], used when `codegen-rustdoc` is disabled so
+/// call sites don't need to be cfg'd out individually.
+#[cfg(not(feature = "codegen-rustdoc"))]
+fn make_doc_attr(_description: Option<&str>, _ctx: &Context) -> TokenStream {
+    TokenStream::new()
+}
+
 pub(crate) fn generate_class_files(
     api: &ExtensionApi,
     ctx: &mut Context,
@@ -170,6 +180,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
         None => quote! { () },
     };
 
+    let class_doc = make_doc_attr(class.description.as_deref(), ctx);
     let constructor = make_constructor(class, ctx);
     let methods = make_methods(&class.methods, class_name, ctx);
     let enums = make_enums(&class.enums, class_name, ctx);
@@ -195,6 +206,7 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
         pub(super) mod re_export {
             use super::*;
 
+            #class_doc
             #[derive(Debug)]
             #[repr(transparent)]
             pub struct #class_name {
@@ -415,13 +427,15 @@ fn make_builtin_methods(
     }
 }
 
-fn make_enums(enums: &Option<Vec<Enum>>, _class_name: &TyName, _ctx: &Context) -> TokenStream {
+fn make_enums(enums: &Option<Vec<Enum>>, _class_name: &TyName, ctx: &Context) -> TokenStream {
     let enums = match enums {
         Some(e) => e,
         None => return TokenStream::new(),
     };
 
-    let definitions = enums.iter().map(util::make_enum_definition);
+    let definitions = enums
+        .iter()
+        .map(|enum_| util::make_enum_definition(enum_, ctx));
 
     quote! {
         #( #definitions )*
@@ -471,5 +485,409 @@ fn is_method_excluded(method: &ClassMethod, #[allow(unused_variables)] ctx: &mut
     //   E.g.: AudioEffectInstance::_process(const void*, AudioFrame*, int)
     //   TODO decide what to do with them, overriding in a type-safe way?
     //
-    // * Methods accepting pointers are often supplementary
+    // Methods accepting/returning raw pointers (e.g. TextServer::font_set_data_ptr()) are NOT
+    // excluded here -- they're generated as `unsafe fn` by make_method_definition, with the
+    // pointer/length/aliasing obligations spelled out in a `# Safety` doc section. See
+    // `pointer_param_names`/`make_safety_doc`.
+
+    if method.name.starts_with('_') && method.is_virtual {
+        return true;
+    }
+
+    #[cfg(not(feature = "codegen-full"))]
+    {
+        let args_excluded = method
+            .arguments
+            .iter()
+            .flatten()
+            .any(|arg| !is_pointer_type(&arg.type_) && is_type_excluded(&arg.type_, ctx));
+
+        let return_excluded = method.return_value.as_ref().map_or(false, |ret| {
+            !is_pointer_type(&ret.type_) && is_type_excluded(&ret.type_, ctx)
+        });
+
+        if args_excluded || return_excluded {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Whether Rust values of this builtin type are cheap to copy (and thus should be taken by value in
+/// generated signatures), or own a heap allocation / ref-counted handle (and thus should be taken by
+/// shared reference, to avoid an implicit clone on every call).
+///
+/// This mirrors `BuiltinTypeInfo::has_destructor` from `central_generator` (a type is non-copy here
+/// exactly when Godot registers a destructor for it): the hand-written list below is kept in sync
+/// with that flag by hand rather than threaded through `Context`, since `make_central_items` builds
+/// `BuiltinTypeInfo` per-type from `extension_api.json` while this function only ever sees a
+/// already-classified `RustTy::BuiltinIdent`, with no `Context` plumbing between the two today.
+fn is_builtin_copy_type(builtin_ident: &Ident) -> bool {
+    const NON_COPY_BUILTINS: &[&str] = &[
+        "GString",
+        "StringName",
+        "NodePath",
+        "Array",
+        "Dictionary",
+        "Variant",
+        "Callable",
+        "Signal",
+        "PackedByteArray",
+        "PackedInt32Array",
+        "PackedInt64Array",
+        "PackedFloat32Array",
+        "PackedFloat64Array",
+        "PackedStringArray",
+        "PackedVector2Array",
+        "PackedVector3Array",
+        "PackedColorArray",
+    ];
+
+    !NON_COPY_BUILTINS.contains(&builtin_ident.to_string().as_str())
+}
+
+/// A single classified method parameter: its declaration in the generated signature, and the
+/// expression used to obtain its ptrcall-argument pointer at the call site.
+struct ParamDecl {
+    decl: TokenStream,
+    arg_ptr_expr: TokenStream,
+}
+
+/// Whether a Godot C++ type string (as used for argument/return types) is a raw pointer, e.g.
+/// `"const void*"` or `"AudioFrame*"`.
+fn is_pointer_type(godot_type: &str) -> bool {
+    godot_type.trim_end().ends_with('*')
+}
+
+/// Classifies a raw-pointer parameter: `"const T*"` becomes `*const T`, `"T*"` becomes `*mut T`,
+/// with `void` mapped to `std::ffi::c_void`. The pointee is passed through as a bare identifier
+/// rather than via `to_rust_type`/`RustTy`, since a raw pointee type (e.g. `AudioFrame`) isn't one
+/// of the categories `RustTy` models -- it's just the engine/builtin type already in scope via this
+/// file's `crate::engine::*`/`crate::builtin::*` glob imports.
+fn make_pointer_param(param_name: &str, godot_type: &str) -> ParamDecl {
+    let param_ident = safe_ident(param_name);
+
+    let trimmed = godot_type.trim().trim_end_matches('*').trim();
+    let (is_const, pointee_name) = match trimmed.strip_prefix("const") {
+        Some(rest) => (true, rest.trim()),
+        None => (false, trimmed),
+    };
+
+    let pointee: TokenStream = if pointee_name == "void" {
+        quote! { std::ffi::c_void }
+    } else {
+        let pointee_ident = format_ident!("{}", pointee_name);
+        quote! { #pointee_ident }
+    };
+
+    let ptr_ty = if is_const {
+        quote! { *const #pointee }
+    } else {
+        quote! { *mut #pointee }
+    };
+
+    ParamDecl {
+        decl: quote! { #param_ident: #ptr_ty },
+        arg_ptr_expr: quote! { #param_ident as sys::GDExtensionConstTypePtr },
+    }
+}
+
+/// Classifies one parameter's Godot type into a Rust parameter declaration, choosing a shared
+/// reference for non-`Copy` builtins and typed arrays (so callers don't pay for an implicit clone
+/// on every call), `impl AsArg<_>` for engine objects, and by-value for `Copy` builtins
+/// (primitives, vector/color math types, enums).
+fn make_param(param_name: &str, godot_type: &str, ctx: &mut Context) -> ParamDecl {
+    if is_pointer_type(godot_type) {
+        return make_pointer_param(param_name, godot_type);
+    }
+
+    let param_ident = safe_ident(param_name);
+    let rust_ty = to_rust_type(godot_type, ctx);
+
+    match &rust_ty {
+        RustTy::BuiltinIdent(builtin_ident) if is_builtin_copy_type(builtin_ident) => ParamDecl {
+            decl: quote! { #param_ident: #rust_ty },
+            arg_ptr_expr: quote! { sys::GodotFfi::sys(&#param_ident) },
+        },
+        RustTy::EngineEnum { .. } => ParamDecl {
+            decl: quote! { #param_ident: #rust_ty },
+            arg_ptr_expr: quote! { sys::GodotFfi::sys(&#param_ident) },
+        },
+        RustTy::EngineClass { .. } => ParamDecl {
+            decl: quote! { #param_ident: impl AsArg<#rust_ty> },
+            arg_ptr_expr: quote! {
+                {
+                    let #param_ident = #param_ident.as_arg();
+                    sys::GodotFfi::sys(&#param_ident)
+                }
+            },
+        },
+        // Non-Copy builtins (GString, Array, Dictionary, Variant, Packed*Array, ...) and
+        // EngineArray (TypedArray<T>) -- taken by shared reference.
+        _ => ParamDecl {
+            decl: quote! { #param_ident: &#rust_ty },
+            arg_ptr_expr: quote! { sys::GodotFfi::sys(#param_ident) },
+        },
+    }
+}
+
+/// Builds the parameter declarations and ptrcall argument-pointer expressions for a method's whole
+/// argument list, via [`make_param`].
+fn make_params<'a>(
+    arguments: impl Iterator<Item = (&'a str, &'a str)>,
+    ctx: &mut Context,
+) -> Vec<ParamDecl> {
+    arguments
+        .map(|(name, ty)| make_param(name, ty, ctx))
+        .collect()
+}
+
+/// Builds this method's return-type declaration and the expression that produces its return value,
+/// given that the raw ptrcall destination slot is filled by the generated `__call` closure.
+fn make_return(return_type: Option<&str>, ctx: &mut Context) -> (TokenStream, TokenStream) {
+    let Some(return_type) = return_type else {
+        return (quote! {}, quote! { () });
+    };
+
+    if is_pointer_type(return_type) {
+        // Mirrors make_pointer_param's "T*" -> "*mut T" mapping; see there for the rationale.
+        let trimmed = return_type.trim().trim_end_matches('*').trim();
+        let (is_const, pointee_name) = match trimmed.strip_prefix("const") {
+            Some(rest) => (true, rest.trim()),
+            None => (false, trimmed),
+        };
+        let pointee: TokenStream = if pointee_name == "void" {
+            quote! { std::ffi::c_void }
+        } else {
+            let pointee_ident = format_ident!("{}", pointee_name);
+            quote! { #pointee_ident }
+        };
+        let (ptr_ty, null_expr) = if is_const {
+            (quote! { *const #pointee }, quote! { std::ptr::null() })
+        } else {
+            (quote! { *mut #pointee }, quote! { std::ptr::null_mut() })
+        };
+
+        return (
+            quote! { -> #ptr_ty },
+            quote! {
+                {
+                    let mut __ret: #ptr_ty = #null_expr;
+                    __call(std::ptr::addr_of_mut!(__ret) as sys::GDExtensionTypePtr);
+                    __ret
+                }
+            },
+        );
+    }
+
+    let rust_ty = to_rust_type(return_type, ctx);
+    let return_decl = rust_ty.return_decl();
+
+    let init_expr = match &rust_ty {
+        RustTy::EngineClass { .. } => quote! {
+            <Option<#rust_ty> as sys::GodotFfi>::from_sys_init(|return_ptr| {
+                __call(return_ptr);
+            })
+        },
+        _ => quote! {
+            <#rust_ty as sys::GodotFfi>::from_sys_init(|return_ptr| {
+                __call(return_ptr);
+            })
+        },
+    };
+
+    (return_decl, init_expr)
+}
+
+/// Names of all raw-pointer-typed arguments of a method, in declaration order; non-empty iff the
+/// generated method must be `unsafe fn` (see `make_safety_doc`).
+fn pointer_param_names<'a>(arguments: impl Iterator<Item = (&'a str, &'a str)>) -> Vec<&'a str> {
+    arguments
+        .filter(|(_, ty)| is_pointer_type(ty))
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// Builds a `# Safety` doc section listing the pointer validity/length/aliasing obligations the
+/// caller must uphold for each raw-pointer parameter, plus the `unsafe` keyword to splice into the
+/// function signature. Returns empty `TokenStream`s for both if `pointer_params` is empty.
+fn make_safety_doc(pointer_params: &[&str]) -> (TokenStream, TokenStream) {
+    if pointer_params.is_empty() {
+        return (TokenStream::new(), TokenStream::new());
+    }
+
+    let mut lines = vec![
+        "# Safety".to_string(),
+        String::new(),
+        "This method is exposed by Godot as taking raw pointer arguments. The caller must ensure:"
+            .to_string(),
+    ];
+    for name in pointer_params {
+        lines.push(format!(
+            "- `{name}` is a valid, properly aligned pointer to memory of the size and type Godot \
+             expects for this parameter, for the entire duration of this call."
+        ));
+    }
+    lines.push(
+        "- No other code concurrently reads or writes through an aliasing pointer while this call \
+         executes."
+            .to_string(),
+    );
+
+    let doc_lines = lines.iter().map(|line| quote! { #[doc = #line] });
+    (quote! { #( #doc_lines )* }, quote! { unsafe })
+}
+
+fn make_method_definition(method: &ClassMethod, class_name: &TyName, ctx: &mut Context) -> TokenStream {
+    if is_method_excluded(method, ctx) {
+        return TokenStream::new();
+    }
+
+    let method_name = safe_ident(&to_snake_case(&method.name));
+    let godot_method_name = &method.name;
+    let godot_class_name = &class_name.godot_ty;
+    let hash = method.hash.unwrap_or(0);
+
+    let arguments = || method.arguments.iter().flatten();
+
+    let params = make_params(
+        arguments().map(|arg| (arg.name.as_str(), arg.type_.as_str())),
+        ctx,
+    );
+    let param_decls = params.iter().map(|p| &p.decl);
+    let arg_ptr_exprs = params.iter().map(|p| &p.arg_ptr_expr);
+
+    let (return_decl, return_init) = make_return(
+        method.return_value.as_ref().map(|ret| ret.type_.as_str()),
+        ctx,
+    );
+
+    let receiver = if method.is_static {
+        quote! {}
+    } else {
+        quote! { &self, }
+    };
+    let self_ptr = if method.is_static {
+        quote! { std::ptr::null_mut() }
+    } else {
+        quote! { self.object_ptr }
+    };
+
+    let method_doc = make_doc_attr(method.description.as_deref(), ctx);
+
+    let mut pointer_params = pointer_param_names(arguments().map(|arg| (arg.name.as_str(), arg.type_.as_str())));
+    if method
+        .return_value
+        .as_ref()
+        .is_some_and(|ret| is_pointer_type(&ret.type_))
+    {
+        pointer_params.push("the return value");
+    }
+    let (safety_doc, unsafe_kw) = make_safety_doc(&pointer_params);
+
+    quote! {
+        #method_doc
+        #safety_doc
+        pub #unsafe_kw fn #method_name(#receiver #( #param_decls ),* ) #return_decl {
+            unsafe {
+                let __method_bind = sys::interface_fn!(classdb_get_method_bind)(
+                    StringName::from(#godot_class_name).string_sys(),
+                    StringName::from(#godot_method_name).string_sys(),
+                    #hash,
+                );
+                let __args = [ #( #arg_ptr_exprs ),* ];
+                let __call = |return_ptr| {
+                    sys::interface_fn!(object_method_bind_ptrcall)(
+                        __method_bind,
+                        #self_ptr,
+                        __args.as_ptr(),
+                        return_ptr,
+                    );
+                };
+
+                #return_init
+            }
+        }
+    }
+}
+
+fn make_builtin_method_definition(
+    method: &BuiltinClassMethod,
+    class_name: &TyName,
+    type_info: &BuiltinTypeInfo,
+    ctx: &mut Context,
+) -> TokenStream {
+    let method_name = safe_ident(&to_snake_case(&method.name));
+    let godot_method_name = &method.name;
+    let variant_type = &type_info.type_names.sys_variant_type;
+    let hash = method.hash.unwrap_or(0);
+
+    let arguments = || method.arguments.iter().flatten();
+
+    let params = make_params(
+        arguments().map(|arg| (arg.name.as_str(), arg.type_.as_str())),
+        ctx,
+    );
+    let param_decls = params.iter().map(|p| &p.decl);
+    let arg_ptr_exprs = params.iter().map(|p| &p.arg_ptr_expr);
+
+    let (return_decl, return_init) = make_return(
+        method.return_type.as_deref(),
+        ctx,
+    );
+
+    let receiver = if method.is_static {
+        quote! {}
+    } else {
+        quote! { &self, }
+    };
+    let self_ptr = if method.is_static {
+        quote! { std::ptr::null_mut() }
+    } else {
+        quote! { self.sys_ptr }
+    };
+
+    // Builtin methods are looked up by variant type rather than class name, so `class_name` (used
+    // by `make_method_definition`'s engine-class counterpart) isn't needed for the lookup itself --
+    // the parameter still exists for API symmetry and future builtin-specific special-casing.
+    let _ = &class_name.godot_ty;
+
+    let method_doc = make_doc_attr(method.description.as_deref(), ctx);
+
+    let mut pointer_params = pointer_param_names(arguments().map(|arg| (arg.name.as_str(), arg.type_.as_str())));
+    if method
+        .return_type
+        .as_deref()
+        .is_some_and(is_pointer_type)
+    {
+        pointer_params.push("the return value");
+    }
+    let (safety_doc, unsafe_kw) = make_safety_doc(&pointer_params);
+
+    quote! {
+        #method_doc
+        #safety_doc
+        pub #unsafe_kw fn #method_name(#receiver #( #param_decls ),* ) #return_decl {
+            unsafe {
+                let __method_bind = sys::interface_fn!(variant_get_ptr_builtin_method)(
+                    #variant_type,
+                    StringName::from(#godot_method_name).string_sys(),
+                    #hash,
+                );
+                let __args = [ #( #arg_ptr_exprs ),* ];
+                let __call = |return_ptr| {
+                    __method_bind(
+                        #self_ptr,
+                        __args.as_ptr(),
+                        return_ptr,
+                        __args.len() as i32,
+                    );
+                };
+
+                #return_init
+            }
+        }
+    }
+}
     //   E.g.: TextServer::font_set_data_ptr() -- in addition to TextServer::font_set_data().
\ No newline at end of file
@@ -0,0 +1,131 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Converts Godot's BBCode class-reference descriptions into `#[doc = "..."]` attributes.
+//!
+//! Godot's extension API ships a `description` field (BBCode, as used in the editor's built-in
+//! help) on classes, methods, enums, and enumerators. This module turns that text into Markdown
+//! suitable for Rustdoc, and is the only part of doc generation gated behind `codegen-rustdoc` --
+//! the call sites in `class_generator.rs`/`util.rs` read the `description` field unconditionally
+//! and, when the feature is off, hand it to a no-op `make_doc_attr` stand-in instead, so doc-less
+//! builds don't pay for the conversion.
+
+use crate::Context;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Rewrites a single BBCode description into GitHub-flavored Markdown.
+///
+/// Handles the small set of tags Godot's class reference actually uses:
+/// - `[code]...[/code]` / `[codeblock]...[/codeblock]` -> `` `...` `` / fenced code block
+/// - `[b]...[/b]` / `[i]...[/i]` -> `**...**` / `_..._`
+/// - `[method Name]`, `[member Name]`, `[constant Name]`, `[enum Name]` -> an intra-doc link
+///   (`` [`Name`] ``) if `Name` (optionally `Class.member`) resolves to a known class via `ctx`,
+///   otherwise a plain code span, since an intra-doc link to a class gdext doesn't generate would
+///   fail to resolve and break the doc build.
+/// - `[Class]` (bare class reference) -> same resolution as above.
+///
+/// Any other/unknown tag is stripped, keeping its inner text.
+pub(crate) fn bbcode_to_markdown(bbcode: &str, ctx: &Context) -> String {
+    let mut out = String::with_capacity(bbcode.len());
+    let mut rest = bbcode;
+
+    while let Some(open) = rest.find('[') {
+        out.push_str(&rest[..open]);
+        rest = &rest[open..];
+
+        let Some(close) = rest.find(']') else {
+            // Unterminated tag; emit the rest verbatim and stop.
+            out.push_str(rest);
+            rest = "";
+            break;
+        };
+
+        let tag = &rest[1..close];
+        rest = &rest[close + 1..];
+
+        match tag {
+            "code" => {
+                let end_tag = "[/code]";
+                if let Some(end) = rest.find(end_tag) {
+                    out.push('`');
+                    out.push_str(&rest[..end]);
+                    out.push('`');
+                    rest = &rest[end + end_tag.len()..];
+                } else {
+                    out.push('`');
+                }
+            }
+            "codeblock" => {
+                let end_tag = "[/codeblock]";
+                if let Some(end) = rest.find(end_tag) {
+                    out.push_str("\n```gdscript\n");
+                    out.push_str(rest[..end].trim_matches('\n'));
+                    out.push_str("\n```\n");
+                    rest = &rest[end + end_tag.len()..];
+                }
+            }
+            "b" => out.push_str("**"),
+            "/b" => out.push_str("**"),
+            "i" => out.push('_'),
+            "/i" => out.push('_'),
+            _ if tag.starts_with("method ")
+                || tag.starts_with("member ")
+                || tag.starts_with("constant ")
+                || tag.starts_with("enum ") =>
+            {
+                let name = tag.split_once(' ').map(|(_, n)| n).unwrap_or(tag);
+                out.push_str(&resolve_reference(name, ctx));
+            }
+            _ if !tag.is_empty()
+                && tag
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_ascii_uppercase()) =>
+            {
+                out.push_str(&resolve_reference(tag, ctx));
+            }
+            _ => {
+                // Unknown tag (e.g. [url], [param]); drop it, keep surrounding text untouched.
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Renders a `Class` or `Class.member` reference as a Rust intra-doc link if `Class` is a class
+/// gdext actually generates bindings for, otherwise as a plain code span (an intra-doc link to a
+/// class that was excluded by `codegen-full`/`special_cases::is_class_deleted` would be a broken
+/// link, which `rustdoc` treats as a hard error under `#[deny(rustdoc::broken_intra_doc_links)]`).
+fn resolve_reference(reference: &str, ctx: &Context) -> String {
+    let class = reference.split('.').next().unwrap_or(reference);
+
+    if ctx.is_known_class(class) {
+        format!("[`{reference}`](crate::engine::{class})")
+    } else {
+        format!("`{reference}`")
+    }
+}
+
+/// Builds the `#[doc = "..."]` attributes for a single BBCode `description`, one attribute per
+/// output line (so rustfmt keeps each line readable instead of one `\n`-embedded literal).
+///
+/// Returns an empty [`TokenStream`] for `None`/empty descriptions.
+pub(crate) fn make_doc_attr(description: Option<&str>, ctx: &Context) -> TokenStream {
+    let Some(description) = description.filter(|s| !s.trim().is_empty()) else {
+        return TokenStream::new();
+    };
+
+    let markdown = bbcode_to_markdown(description, ctx);
+    let lines = markdown.lines().map(|line| quote! { #[doc = #line] });
+
+    quote! {
+        #( #lines )*
+    }
+}
@@ -9,6 +9,8 @@ mod api_parser;
 mod central_generator;
 mod class_generator;
 mod context;
+#[cfg(feature = "codegen-rustdoc")]
+mod doc_gen;
 mod godot_exe;
 mod godot_version;
 mod special_cases;
@@ -35,13 +37,73 @@ use proc_macro2::{Ident, TokenStream};
 use quote::{quote, ToTokens};
 use std::path::{Path, PathBuf};
 
+/// Environment variable pointing at a custom `extension_api.json`, for users building against a
+/// patched or modded Godot whose API differs from the version gdext bundles (extra classes,
+/// engine-specific additions, ...). When unset, codegen uses the bundled API as before.
+const API_JSON_ENV_VAR: &str = "GODOT_EXTENSION_API_JSON";
+
+/// Where the [`ExtensionApi`] driving codegen comes from.
+enum ApiSource {
+    /// The `extension_api.json` gdext bundles and was written against.
+    Bundled,
+    /// A user-supplied file, resolved from [`API_JSON_ENV_VAR`].
+    Custom(PathBuf),
+}
+
+impl ApiSource {
+    fn from_env() -> Self {
+        match std::env::var_os(API_JSON_ENV_VAR) {
+            Some(path) if !path.is_empty() => Self::Custom(PathBuf::from(path)),
+            _ => Self::Bundled,
+        }
+    }
+}
+
+/// Loads the [`ExtensionApi`] driving codegen, honoring [`API_JSON_ENV_VAR`] if set.
+///
+/// A custom file's `build_config` is validated against the set gdext's generators actually know
+/// how to emit for, before it's handed to the rest of the pipeline -- an unsupported/garbled
+/// `build_config` would otherwise surface as a confusing failure deep inside `Context::build_from_api`
+/// or an individual generator, rather than as a clear "this file isn't usable" error up front.
+fn load_api(watch: &mut StopWatch) -> (ExtensionApi, &'static str) {
+    match ApiSource::from_env() {
+        ApiSource::Bundled => load_extension_api(watch),
+        ApiSource::Custom(path) => {
+            let (api, build_config) = api_parser::load_extension_api_from_file(&path, watch)
+                .unwrap_or_else(|e| {
+                    panic!(
+                        "failed to load custom extension API from {} (set via {API_JSON_ENV_VAR}): {e}",
+                        path.display()
+                    )
+                });
+
+            validate_build_config(build_config, &path);
+            (api, build_config)
+        }
+    }
+}
+
+/// Panics with a clear message if `build_config` (as declared by a custom `extension_api.json`)
+/// isn't one of the configurations gdext's generators are written to emit for.
+fn validate_build_config(build_config: &str, source_path: &Path) {
+    const SUPPORTED_BUILD_CONFIGS: &[&str] = &["float_32", "float_64", "double_32", "double_64"];
+
+    if !SUPPORTED_BUILD_CONFIGS.contains(&build_config) {
+        panic!(
+            "custom extension API at {} declares unsupported build_config `{build_config}`; \
+             expected one of {SUPPORTED_BUILD_CONFIGS:?}",
+            source_path.display()
+        );
+    }
+}
+
 pub fn generate_sys_files(sys_gen_path: &Path) {
     let mut out_files = vec![];
     let mut watch = StopWatch::start();
 
     generate_sys_mod_file(sys_gen_path, &mut out_files);
 
-    let (api, build_config) = load_extension_api(&mut watch);
+    let (api, build_config) = load_api(&mut watch);
     let mut ctx = Context::build_from_api(&api);
     watch.record("build_context");
 
@@ -59,7 +121,7 @@ pub fn generate_core_files(core_gen_path: &Path) {
 
     generate_core_mod_file(core_gen_path, &mut out_files);
 
-    let (api, build_config) = load_extension_api(&mut watch);
+    let (api, build_config) = load_api(&mut watch);
     let mut ctx = Context::build_from_api(&api);
     watch.record("build_context");
 
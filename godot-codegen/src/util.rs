@@ -10,45 +10,180 @@ use crate::{Context, ModName, RustTy, TyName};
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
 
-pub fn make_enum_definition(enum_: &Enum) -> TokenStream {
-    // TODO enums which have unique ords could be represented as Rust enums
-    // This would allow exhaustive matches (or at least auto-completed matches + #[non_exhaustive]). But even without #[non_exhaustive],
-    // this might be a forward compatibility hazard, if Godot deprecates enumerators and adds new ones with existing ords.
+#[cfg(feature = "codegen-rustdoc")]
+use crate::doc_gen::make_doc_attr;
 
-    let enum_name = ident(&enum_.name);
+/// No-op stand-in for [`doc_gen::make_doc_attr`], used when `codegen-rustdoc` is disabled so
+/// call sites don't need to be cfg'd out individually.
+#[cfg(not(feature = "codegen-rustdoc"))]
+fn make_doc_attr(_description: Option<&str>, _ctx: &Context) -> TokenStream {
+    TokenStream::new()
+}
+
+pub fn make_enum_definition(enum_: &Enum, ctx: &Context) -> TokenStream {
+    // Enums backed by a builtin scalar (e.g. `Variant.Type`) already have a hand-written Rust
+    // counterpart elsewhere in this crate; don't generate a second, conflicting one here.
+    if is_builtin_scalar(&enum_.name) {
+        return TokenStream::new();
+    }
 
+    let enum_name = ident(&enum_.name);
+    let enum_doc = make_doc_attr(enum_.description.as_deref(), ctx);
     let values = &enum_.values;
+
+    if enum_.is_bitfield {
+        make_bitfield_definition(&enum_name, &enum_doc, values, ctx)
+    } else {
+        make_enum_as_newtype(&enum_name, &enum_doc, values, ctx)
+    }
+}
+
+/// Generates a `#[repr(transparent)] struct Foo(i32)` newtype with one associated `const` per
+/// enumerator, plus an `EngineEnum` impl.
+///
+/// A real Rust `enum` would be unsound here: Godot can return an ordinal with no matching
+/// enumerator (e.g. a newer engine version added one this binding doesn't know about yet), and
+/// constructing an `enum` from an out-of-range discriminant is immediate UB. The newtype instead
+/// stores the ordinal as-is, so `from_ord`/`ord` are a total, lossless round trip no matter what
+/// the engine hands back -- `try_from_ord` is the fallible counterpart for callers who want to
+/// reject unknown ordinals instead.
+///
+/// This representation is also what lets bitfields (see [`make_bitfield_definition`]) compose
+/// enumerators with `|`: a duplicate-ordinal-safe, OR-combinable value has no equivalent as a
+/// real Rust `enum`, which requires distinct discriminants and can't express a combination of
+/// variants at all.
+fn make_enum_as_newtype(
+    enum_name: &Ident,
+    enum_doc: &TokenStream,
+    values: &[crate::api_parser::EnumConstant],
+    ctx: &Context,
+) -> TokenStream {
     let mut enumerators = Vec::with_capacity(values.len());
-    // let mut matches = Vec::with_capacity(values.len());
-    let mut unique_ords = Vec::with_capacity(values.len());
+    let mut ord_match_arms = Vec::with_capacity(values.len());
 
     for enumerator in values {
-        let name = make_enumerator_name(&enumerator.name, &enum_.name);
+        let name = make_enumerator_name(&enumerator.name, enum_name);
         let ordinal = Literal::i32_unsuffixed(enumerator.value);
+        let enumerator_doc = make_doc_attr(enumerator.description.as_deref(), ctx);
 
         enumerators.push(quote! {
-            pub const #name: Self = Self { ord: #ordinal };
+            #enumerator_doc
+            pub const #name: Self = Self(#ordinal);
+        });
+        ord_match_arms.push(quote! {
+            #ordinal => true,
         });
-        // matches.push(quote! {
-        //     #ordinal => Some(Self::#name),
-        // });
-        unique_ords.push(enumerator.value);
     }
 
-    // They are not necessarily in order
-    unique_ords.sort();
-    unique_ords.dedup();
+    quote! {
+        #enum_doc
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct #enum_name(i32);
 
-    let bitfield_ops = if enum_.is_bitfield {
-        let tokens = quote! {
-            // impl #enum_name {
-            //     pub const UNSET: Self = Self { ord: 0 };
-            // }
-            impl std::ops::BitOr for #enum_name {
-                type Output = Self;
+        impl #enum_name {
+            #( #enumerators )*
 
-                fn bitor(self, rhs: Self) -> Self::Output {
-                    Self { ord: self.ord | rhs.ord }
+            /// Whether `ord` matches one of this enum's known enumerators.
+            fn is_known_ord(ord: i32) -> bool {
+                match ord {
+                    #( #ord_match_arms )*
+                    _ => false,
                 }
             }
-      
\ No newline at end of file
+        }
+
+        impl crate::obj::EngineEnum for #enum_name {
+            fn try_from_ord(ord: i32) -> Option<Self> {
+                Self::is_known_ord(ord).then_some(Self(ord))
+            }
+
+            fn ord(self) -> i32 {
+                self.0
+            }
+
+            /// Total, lossless conversion: unlike the default [`EngineEnum::from_ord`], this never
+            /// panics on an ordinal outside the known set -- it round-trips instead, since the
+            /// in-memory representation has no invalid bit pattern to guard against.
+            fn from_ord(ord: i32) -> Self {
+                Self(ord)
+            }
+        }
+    }
+}
+
+/// Generates the [`make_enum_as_newtype`] definition plus `BitOr`/`BitAnd`/`BitXor`/`Not` and a
+/// `contains`/`is_set` helper, so Godot's `is_bitfield` enums compose naturally as OR-combinable
+/// flags (`Flags::A | Flags::B`).
+fn make_bitfield_definition(
+    enum_name: &Ident,
+    enum_doc: &TokenStream,
+    values: &[crate::api_parser::EnumConstant],
+    ctx: &Context,
+) -> TokenStream {
+    let newtype = make_enum_as_newtype(enum_name, enum_doc, values, ctx);
+
+    quote! {
+        #newtype
+
+        impl #enum_name {
+            /// Whether `self` has all the bits of `flags` set.
+            pub fn contains(self, flags: Self) -> bool {
+                self.0 & flags.0 == flags.0
+            }
+
+            /// Whether the single flag `flag` is set in `self`.
+            ///
+            /// Equivalent to [`contains`](Self::contains), but reads better at a single-flag call site.
+            pub fn is_set(self, flag: Self) -> bool {
+                self.contains(flag)
+            }
+        }
+
+        impl std::ops::BitOr for #enum_name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self::Output {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl std::ops::BitAnd for #enum_name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self::Output {
+                Self(self.0 & rhs.0)
+            }
+        }
+
+        impl std::ops::BitXor for #enum_name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                Self(self.0 ^ rhs.0)
+            }
+        }
+
+        impl std::ops::Not for #enum_name {
+            type Output = Self;
+
+            fn not(self) -> Self::Output {
+                Self(!self.0)
+            }
+        }
+    }
+}
+
+/// Converts a Godot identifier (class, enum, method, ...) to a Rust [`Ident`] as-is; no escaping
+/// is done for names that collide with a Rust keyword.
+fn ident(name: &str) -> Ident {
+    format_ident!("{}", name)
+}
+
+/// Derives the Rust constant/variant name for a single enumerator.
+///
+/// Godot already spells these in `SCREAMING_SNAKE_CASE` (e.g. `PROPERTY_HINT_NONE`), matching Rust's
+/// convention for both associated consts and enum variants, so no case conversion is needed.
+fn make_enumerator_name(enumerator_name: &str, _enum_name: &Ident) -> Ident {
+    format_ident!("{}", enumerator_name)
+}
@@ -5,7 +5,8 @@
  */
 
 use crate::builder::ClassBuilder;
-use crate::builtin::GodotString;
+use crate::builtin::meta::PropertyInfo;
+use crate::builtin::{GodotString, StringName, Variant};
 use crate::obj::Base;
 use crate::obj::GodotClass;
 
@@ -16,8 +17,115 @@ use crate::obj::GodotClass;
 /// * `to_string` method
 /// * Custom register methods (builder style)
 /// * All the lifecycle methods like `ready`, `process` etc.
+/// * Dynamic, data-driven properties that aren't known at compile time
 ///
 /// This trait is special in that it needs to be used in combination with the `#[godot_api]`
 /// proc-macro attribute to ensure proper registration of its methods. All methods have
 /// default implementations, so you can select precisely which functionality you want to have.
-/// Those default implementations are never called howe
\ No newline at end of file
+/// Those default implementations are never called however, the proc macro generates the necessary
+/// registration glue for whichever methods you actually override.
+pub trait GodotExt: GodotClass {
+    /// Godot constructor, called before the object's first ready callback.
+    fn init(base: Base<Self::Base>) -> Self {
+        unimplemented!()
+    }
+
+    /// String representation of the object, returned by GDScript's `str()` and the debugger.
+    fn to_string(&self) -> GodotString {
+        unimplemented!()
+    }
+
+    /// Called when the class is registered with Godot. Use the provided builder to register
+    /// additional, runtime-dependent functionality.
+    fn register_class(builder: &mut ClassBuilder<Self>) {
+        let _ = builder;
+        unimplemented!()
+    }
+
+    /// Called when the node enters the [`SceneTree`](crate::engine::SceneTree).
+    fn ready(&mut self) {
+        unimplemented!()
+    }
+
+    /// Called every process (i.e. idle) frame, with the time elapsed since the last one.
+    fn process(&mut self, delta: f64) {
+        let _ = delta;
+        unimplemented!()
+    }
+
+    /// Called every physics frame, with the time elapsed since the last one.
+    fn physics_process(&mut self, delta: f64) {
+        let _ = delta;
+        unimplemented!()
+    }
+
+    /// Called when the node enters the [`SceneTree`](crate::engine::SceneTree), before [`Self::ready()`].
+    fn enter_tree(&mut self) {
+        unimplemented!()
+    }
+
+    /// Called when the node is about to leave the [`SceneTree`](crate::engine::SceneTree).
+    fn exit_tree(&mut self) {
+        unimplemented!()
+    }
+
+    /// Called for every input event, before [`Self::unhandled_input()`]. Godot consumes the event if the
+    /// viewport is set to handle input locally and the node is focused.
+    fn input(&mut self, event: crate::obj::Gd<crate::engine::InputEvent>) {
+        let _ = event;
+        unimplemented!()
+    }
+
+    /// Called for every input event that was not consumed by [`Self::input()`] or the GUI.
+    fn unhandled_input(&mut self, event: crate::obj::Gd<crate::engine::InputEvent>) {
+        let _ = event;
+        unimplemented!()
+    }
+
+    /// Called when the node receives a Godot notification, identified by its raw integer code.
+    fn notification(&mut self, what: i32) {
+        let _ = what;
+        unimplemented!()
+    }
+
+    /// Called when a [`CanvasItem`](crate::engine::CanvasItem)-derived node needs to redraw itself.
+    fn draw(&mut self) {
+        unimplemented!()
+    }
+
+    /// Returns the list of dynamic properties this object exposes to the editor and GDScript,
+    /// in addition to the statically `#[export]`-ed ones.
+    ///
+    /// Mirrors Godot's `_get_property_list`. Useful for data-driven nodes or scripting bridges
+    /// whose property names/types are not known at compile time.
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        Vec::new()
+    }
+
+    /// Returns the current value of a dynamic property previously advertised through
+    /// [`Self::get_property_list()`], or `None` if `name` is not one of this object's properties.
+    ///
+    /// Mirrors Godot's `_get`.
+    fn get_property(&self, name: StringName) -> Option<Variant> {
+        let _ = name;
+        None
+    }
+
+    /// Attempts to set a dynamic property previously advertised through
+    /// [`Self::get_property_list()`]. Returns `true` if `name` was recognized and handled.
+    ///
+    /// Mirrors Godot's `_set`.
+    fn set_property(&mut self, name: StringName, value: Variant) -> bool {
+        let _ = (name, value);
+        false
+    }
+
+    /// Returns the value a dynamic property should revert to when reset in the editor, or `None`
+    /// if it has no revert value.
+    ///
+    /// Mirrors Godot's `_property_get_revert`.
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        let _ = name;
+        None
+    }
+}
\ No newline at end of file
@@ -5,7 +5,11 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use crate::builtin::meta::SignatureTuple;
+use crate::builtin::StringName;
 use crate::obj::GodotClass;
+use godot_ffi as sys;
+use std::ffi::c_void;
 use std::marker::PhantomData;
 
 mod method;
@@ -22,14 +26,25 @@ where
         Self { _c: PhantomData }
     }
 
-    pub fn virtual_method<'cb, F>(&'cb mut self, name: &'cb str, method: F) -> MethodBuilder<C, F> {
+    /// Starts registering a method named `name` for this class. Takes the same path as
+    /// [`method()`](Self::method): [`MethodBuilder::done()`] registers it with the normal method
+    /// flag, not as a virtual method.
+    pub fn virtual_method<'cb, F>(&'cb mut self, name: &'cb str, method: F) -> MethodBuilder<'cb, C, F> {
+        MethodBuilder::new(self, name, method)
+    }
+
+    /// Registers a regular instance method, callable from GDScript, at runtime.
+    ///
+    /// This is the data-driven counterpart to `#[func]`, for classes whose methods can't be
+    /// expressed through the attribute macro -- notably generic classes, since `#[godot_api]`
+    /// rejects generic `impl` blocks.
+    pub fn method<'cb, F>(&'cb mut self, name: &'cb str, method: F) -> MethodBuilder<'cb, C, F> {
         MethodBuilder::new(self, name, method)
     }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-#[allow(dead_code)] // TODO rm
 #[must_use]
 pub struct MethodBuilder<'cb, C, F> {
     class_builder: &'cb mut ClassBuilder<C>,
@@ -37,7 +52,10 @@ pub struct MethodBuilder<'cb, C, F> {
     method: F,
 }
 
-impl<'cb, C, F> MethodBuilder<'cb, C, F> {
+impl<'cb, C, F> MethodBuilder<'cb, C, F>
+where
+    C: GodotClass,
+{
     pub(super) fn new(class_builder: &'cb mut ClassBuilder<C>, name: &'cb str, method: F) -> Self {
         Self {
             class_builder,
@@ -46,5 +64,88 @@ impl<'cb, C, F> MethodBuilder<'cb, C, F> {
         }
     }
 
-    pub fn done(self) {}
-}
\ No newline at end of file
+    /// Finishes registration: derives this method's argument/return [`PropertyInfo`](crate::builtin::meta::PropertyInfo)s
+    /// from `Sig` and registers the method with the engine via `classdb_register_extension_class_method`.
+    ///
+    /// `Sig` is the [`SignatureTuple`] matching `method`'s actual `(return, param0, param1, ...)`
+    /// signature; it's usually inferred from the call site, otherwise spelled out explicitly as
+    /// `builder.method("name", |this, ...| ...).done::<(Ret, P0, P1)>()`.
+    pub fn done<Sig>(self)
+    where
+        Sig: SignatureTuple,
+        F: Fn(&mut C, Sig::Params) -> Sig::Ret + 'static,
+    {
+        // Only needed to scope this registration to one class; no further use once we have C::CLASS_NAME.
+        let _ = self.class_builder;
+
+        let method_name = StringName::from(self.name);
+
+        let return_info = Sig::property_info(-1, "return").property_sys();
+        let argument_infos: Vec<_> = (0..Sig::PARAM_COUNT as i32)
+            .map(|i| Sig::property_info(i, &format!("arg{i}")).property_sys())
+            .collect();
+
+        // The closure is boxed and leaked: like the bindings `#[func]` generates, a runtime-registered
+        // method is expected to live for as long as the class itself is registered, i.e. the duration
+        // of the program.
+        let method_userdata = Box::into_raw(Box::new(self.method)) as *mut c_void;
+
+        unsafe {
+            let method_info = sys::GDExtensionClassMethodInfo {
+                name: method_name.string_sys(),
+                method_userdata,
+                call_func: Some(varcall_trampoline::<C, F, Sig>),
+                ptrcall_func: Some(ptrcall_trampoline::<C, F, Sig>),
+                method_flags: sys::GDEXTENSION_METHOD_FLAG_NORMAL as u32,
+                has_return_value: true as u8,
+                return_value_info: &return_info as *const _ as *mut _,
+                return_value_metadata: Sig::param_metadata(-1),
+                argument_count: Sig::PARAM_COUNT as u32,
+                arguments_info: argument_infos.as_ptr() as *mut _,
+                arguments_metadata: std::ptr::null_mut(),
+                default_argument_count: 0,
+                default_arguments: std::ptr::null_mut(),
+            };
+
+            sys::interface_fn!(classdb_register_extension_class_method)(
+                sys::get_library(),
+                StringName::from(C::CLASS_NAME).string_sys(),
+                std::ptr::addr_of!(method_info),
+            );
+        }
+    }
+}
+
+/// Variant-boxing trampoline, installed as a [`GDExtensionClassMethodInfo::call_func`] for a
+/// runtime-registered ([`ClassBuilder::method`]) closure.
+unsafe extern "C" fn varcall_trampoline<C, F, Sig>(
+    method_userdata: *mut c_void,
+    instance: sys::GDExtensionClassInstancePtr,
+    args: *const sys::GDExtensionConstVariantPtr,
+    _arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+) where
+    C: GodotClass,
+    F: Fn(&mut C, Sig::Params) -> Sig::Ret + 'static,
+    Sig: SignatureTuple,
+{
+    let method = unsafe { &*(method_userdata as *const F) };
+    unsafe { Sig::varcall(instance, args, ret, err, method, "<runtime-registered method>") };
+}
+
+/// Variant-free trampoline, installed as a [`GDExtensionClassMethodInfo::ptrcall_func`] for a
+/// runtime-registered ([`ClassBuilder::method`]) closure.
+unsafe extern "C" fn ptrcall_trampoline<C, F, Sig>(
+    method_userdata: *mut c_void,
+    instance: sys::GDExtensionClassInstancePtr,
+    args: *const sys::GDExtensionConstTypePtr,
+    ret: sys::GDExtensionTypePtr,
+) where
+    C: GodotClass,
+    F: Fn(&mut C, Sig::Params) -> Sig::Ret + 'static,
+    Sig: SignatureTuple,
+{
+    let method = unsafe { &*(method_userdata as *const F) };
+    unsafe { Sig::ptrcall(instance, args, ret, method, "<runtime-registered method>") };
+}
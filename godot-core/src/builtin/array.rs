@@ -9,7 +9,6 @@ use godot_ffi as sys;
 
 use crate::builtin::meta::VariantMetadata;
 use crate::builtin::*;
-use crate::obj::Share;
 use std::fmt;
 use std::marker::PhantomData;
 use sys::{ffi_methods, interface_fn, GodotFfi};
@@ -31,26 +30,43 @@ use sys::{ffi_methods, interface_fn, GodotFfi};
 ///
 /// # Reference semantics
 ///
-/// Like in GDScript, `Array` acts as a reference type: multiple `Array` instances may
-/// refer to the same underlying array, and changes to one are visible in the other.
+/// Like in GDScript, the underlying array storage is reference-counted and copy-on-write: the data
+/// itself is never implicitly duplicated. What *is* checked at compile time now is who is allowed
+/// to see that storage mutated out from under them -- that's what the `Own` parameter below is
+/// for. If you want an actual copy of the data rather than another view of the same storage, use
+/// [`duplicate_shallow()`] or [`duplicate_deep()`].
 ///
-/// To create a copy that shares data with the original array, use [`Share::share()`]. If you want
-/// to create a copy of the data, use [`duplicate_shallow()`] or [`duplicate_deep()`].
+/// # Thread safety and the `Own` parameter
 ///
-/// # Thread safety
+/// `Array<T, Own>` carries a second, typestate parameter describing who else might be looking at
+/// its underlying storage, so that the Rust compiler -- not just external synchronization -- can
+/// rule out unsound concurrent mutation. There are three states:
 ///
-/// Usage is safe if the `Array` is used on a single thread only. Concurrent reads on
-/// different threads are also safe, but any writes must be externally synchronized. The Rust
-/// compiler will enforce this as long as you use only Rust threads, but it cannot protect against
-/// concurrent modification on other threads (e.g. created through GDScript).
+/// - [`Unique`] (the default returned by every constructor): no other handle refers to this
+///   array's storage. Mutating methods (`set`, `push`, `clear`, ...) are only available here and
+///   on [`ThreadLocal`].
+/// - [`Shared`]: obtained by consuming a `Unique` array with [`Array::share()`]. May be cloned and
+///   sent across threads freely, but only the read-only methods (`get`, `len`, `contains`, ...)
+///   are available -- there is no safe way to mutate storage that another handle might be reading
+///   concurrently.
+/// - [`ThreadLocal`]: like `Unique` (mutable), but obtained via the `unsafe`
+///   [`Array::into_thread_local()`], which asserts the array will only ever be touched from the
+///   current thread -- use this for a handle whose uniqueness the type system can't otherwise see,
+///   e.g. one reconstructed from a raw pointer Godot handed back on the same thread.
+///
+/// An existing, unconverted `Array<T>` (no second argument) defaults to `Own = `[`Shared`], since
+/// that's the safe assumption for an array whose provenance isn't otherwise known (e.g. one
+/// received from a Godot API call) -- call [`assume_unique()`](Array::assume_unique) on it if you
+/// know otherwise.
 // `T` must be restricted to `VariantMetadata` in the type, because `Drop` can only be implemented
 // for `T: VariantMetadata` because `drop()` requires `sys_mut()`, which is on the `GodotFfi`
 // trait, whose `from_sys_init()` requires `Default`, which is only implemented for `T:
 // VariantMetadata`. Whew. This could be fixed by splitting up `GodotFfi` if desired.
 #[repr(C)]
-pub struct Array<T: VariantMetadata> {
+pub struct Array<T: VariantMetadata, Own: ArrayOwnership = Shared> {
     opaque: sys::types::OpaqueArray,
     _phantom: PhantomData<T>,
+    _own: PhantomData<Own>,
 }
 
 /// A Godot `Array` without an assigned type.
@@ -69,12 +85,135 @@ impl_builtin_froms!(VariantArray;
     PackedVector3Array => array_from_packed_vector3_array,
 );
 
-impl<T: VariantMetadata> Array<T> {
+/// Borrowing iterator over the elements of an [`Array<T>`], obtained through
+/// [`Array::iter_shared()`].
+///
+/// Reads the array's length once, up front; it doesn't track further structural changes (e.g.
+/// through another handle sharing the same underlying, reference-counted array) made while the
+/// iterator is live, though each element access still goes through the bounds-checked
+/// [`get()`](Array::get), which panics if the array has shrunk below that cached length.
+pub struct Iter<'a, T: VariantMetadata, Own: ArrayOwnership = Shared> {
+    array: &'a Array<T, Own>,
+    next_index: usize,
+    len: usize,
+}
+
+impl<'a, T: VariantMetadata + FromVariant + ToVariant, Own: ArrayOwnership> Iterator
+    for Iter<'a, T, Own>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next_index >= self.len {
+            return None;
+        }
+
+        let value = self.array.get(self.next_index);
+        self.next_index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next_index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// By-value iterator over the elements of an [`Array<T, Own>`], obtained through
+/// `IntoIterator::into_iter`. See [`Iter`] for the borrowing equivalent.
+pub struct IntoIter<T: VariantMetadata, Own: ArrayOwnership = Shared> {
+    array: Array<T, Own>,
+    next_index: usize,
+    len: usize,
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant, Own: ArrayOwnership> Iterator
+    for IntoIter<T, Own>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.next_index >= self.len {
+            return None;
+        }
+
+        let value = self.array.get(self.next_index);
+        self.next_index += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.next_index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant, Own: ArrayOwnership> IntoIterator
+    for Array<T, Own>
+{
+    type Item = T;
+    type IntoIter = IntoIter<T, Own>;
+
+    fn into_iter(self) -> IntoIter<T, Own> {
+        let len = self.len();
+        IntoIter {
+            array: self,
+            next_index: 0,
+            len,
+        }
+    }
+}
+
+impl<'a, T: VariantMetadata + FromVariant + ToVariant, Own: ArrayOwnership> IntoIterator
+    for &'a Array<T, Own>
+{
+    type Item = T;
+    type IntoIter = Iter<'a, T, Own>;
+
+    fn into_iter(self) -> Iter<'a, T, Own> {
+        self.iter_shared()
+    }
+}
+
+/// Collects into a freshly (uniquely) constructed array, going through the typed [`push`](
+/// Array::push) path so the runtime element type ends up stamped the same way `array![...]` does.
+impl<T: VariantMetadata + FromVariant + ToVariant> FromIterator<T> for Array<T, Unique> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant, Own: MutableOwnership> Extend<T>
+    for Array<T, Own>
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: VariantMetadata + FromVariant + ToVariant, Own: ArrayOwnership> Array<T, Own> {
+    /// Returns a borrowing iterator over this array's elements, converting each from [`Variant`]
+    /// to `T`.
+    pub fn iter_shared(&self) -> Iter<T, Own> {
+        Iter {
+            array: self,
+            next_index: 0,
+            len: self.len(),
+        }
+    }
+}
+
+impl<T: VariantMetadata, Own: ArrayOwnership> Array<T, Own> {
     fn from_opaque(opaque: sys::types::OpaqueArray) -> Self {
         // Note: type is not yet checked at this point, because array has not yet been initialized!
         Self {
             opaque,
             _phantom: PhantomData,
+            _own: PhantomData,
         }
     }
 
@@ -99,6 +238,90 @@ impl<T: VariantMetadata> Array<T> {
         self.as_inner().hash().try_into().unwrap()
     }
 
+    /// Asserts that the given index refers to an existing element.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    fn check_bounds(&self, index: usize) {
+        let len = self.len();
+        assert!(
+            index < len,
+            "Array index {index} is out of bounds: length is {len}",
+        );
+    }
+
+    /// Returns a pointer to the element at the given index.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds.
+    fn ptr(&self, index: usize) -> *const Variant {
+        self.check_bounds(index);
+
+        // SAFETY: We just checked that the index is not out of bounds.
+        unsafe { self.ptr_unchecked(index) }
+    }
+
+    /// Returns a pointer to the element at the given index.
+    ///
+    /// # Safety
+    ///
+    /// Calling this with an out-of-bounds index is undefined behavior.
+    unsafe fn ptr_unchecked(&self, index: usize) -> *const Variant {
+        let variant_ptr = interface_fn!(array_operator_index_const)(self.sys(), to_i64(index));
+        Variant::ptr_from_sys(variant_ptr)
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerArray {
+        // SAFETY: The memory layout of `Array<T, Own>` does not depend on `T` or `Own`.
+        inner::InnerArray::from_outer_typed(self)
+    }
+
+    /// Duplicates this array, returning a new array with a separate copy of the elements -- unlike
+    /// [`share()`](Array::share), changes to one no longer affect the other. The copy preserves
+    /// this array's runtime element type, and -- like every constructor in this module -- starts
+    /// out uniquely owned.
+    ///
+    /// Elements are copied shallowly: nested arrays/dictionaries still share their own storage with
+    /// the original's. Use [`duplicate_deep()`](Self::duplicate_deep) to copy those recursively too.
+    pub fn duplicate_shallow(&self) -> Array<T, Unique> {
+        self.duplicate(false)
+    }
+
+    /// Like [`duplicate_shallow()`](Self::duplicate_shallow), but nested arrays and dictionaries are
+    /// duplicated recursively as well, rather than sharing storage with the original's elements.
+    pub fn duplicate_deep(&self) -> Array<T, Unique> {
+        self.duplicate(true)
+    }
+
+    fn duplicate(&self, deep: bool) -> Array<T, Unique> {
+        let copy = self.as_inner().duplicate(deep);
+        Array::from_opaque(copy.opaque)
+    }
+
+    /// Returns a new array containing the elements in `[begin, end)`, taking every `step`-th
+    /// element (`step` defaults to `1` if `None`). Preserves this array's runtime element type, and
+    /// -- like [`duplicate_shallow()`](Self::duplicate_shallow) -- starts out uniquely owned.
+    ///
+    /// # Panics
+    /// If `step` is `Some(0)`.
+    pub fn subarray(&self, begin: usize, end: usize, step: Option<i64>) -> Array<T, Unique> {
+        let step = step.unwrap_or(1);
+        assert_ne!(step, 0, "subarray step cannot be 0");
+
+        let slice = self
+            .as_inner()
+            .slice(to_i64(begin), to_i64(end), step, false);
+
+        Array::from_opaque(slice.opaque)
+    }
+}
+
+/// Mutating methods, available only where `Own` structurally or assertedly guarantees no other
+/// handle observes the mutation concurrently -- see the type-level docs on [`Array`].
+impl<T: VariantMetadata, Own: MutableOwnership> Array<T, Own> {
     /// Clears the array, removing all elements.
     pub fn clear(&mut self) {
         self.as_inner().clear();
@@ -132,31 +355,6 @@ impl<T: VariantMetadata> Array<T> {
         self.as_inner().shuffle();
     }
 
-    /// Asserts that the given index refers to an existing element.
-    ///
-    /// # Panics
-    ///
-    /// If `index` is out of bounds.
-    fn check_bounds(&self, index: usize) {
-        let len = self.len();
-        assert!(
-            index < len,
-            "Array index {index} is out of bounds: length is {len}",
-        );
-    }
-
-    /// Returns a pointer to the element at the given index.
-    ///
-    /// # Panics
-    ///
-    /// If `index` is out of bounds.
-    fn ptr(&self, index: usize) -> *const Variant {
-        self.check_bounds(index);
-
-        // SAFETY: We just checked that the index is not out of bounds.
-        unsafe { self.ptr_unchecked(index) }
-    }
-
     /// Returns a mutable pointer to the element at the given index.
     ///
     /// # Panics
@@ -169,16 +367,6 @@ impl<T: VariantMetadata> Array<T> {
         unsafe { self.ptr_mut_unchecked(index) }
     }
 
-    /// Returns a pointer to the element at the given index.
-    ///
-    /// # Safety
-    ///
-    /// Calling this with an out-of-bounds index is undefined behavior.
-    unsafe fn ptr_unchecked(&self, index: usize) -> *const Variant {
-        let variant_ptr = interface_fn!(array_operator_index_const)(self.sys(), to_i64(index));
-        Variant::ptr_from_sys(variant_ptr)
-    }
-
     /// Returns a mutable pointer to the element at the given index.
     ///
     /// # Safety
@@ -188,16 +376,104 @@ impl<T: VariantMetadata> Array<T> {
         let variant_ptr = interface_fn!(array_operator_index)(self.sys(), to_i64(index));
         Variant::ptr_from_sys_mut(variant_ptr)
     }
+}
+
+impl<T: VariantMetadata> Array<T, Unique> {
+    /// Converts this uniquely-owned array into a [`Shared`] handle, which may be cloned and sent
+    /// across threads for reads. This consumes the `Unique` handle: further mutation has to go
+    /// through whichever `Shared` handle you keep (there isn't one -- `Shared` is read-only -- so
+    /// in practice this is a one-way trip out of mutability), or through a fresh [`assume_unique()`
+    /// ](Array::assume_unique)/[`into_thread_local()`](Array::into_thread_local) once you can once
+    /// again account for every handle.
+    pub fn share(self) -> Array<T, Shared> {
+        Array {
+            opaque: self.opaque,
+            _phantom: PhantomData,
+            _own: PhantomData,
+        }
+    }
+}
 
-    #[doc(hidden)]
-    pub fn as_inner(&self) -> inner::InnerArray {
-        // SAFETY: The memory layout of `TypedArray<T>` does not depend on `T`.
-        inner::InnerArray::from_outer_typed(self)
+impl<T: VariantMetadata, Own: ArrayOwnership> Array<T, Own> {
+    /// Asserts that no other `Array` handle refers to this array's underlying storage, allowing it
+    /// to be mutated again.
+    ///
+    /// # Safety
+    /// The caller must ensure no other handle refers to the same underlying storage for as long as
+    /// the returned handle (or any handle derived from it) is used mutably.
+    pub unsafe fn assume_unique(self) -> Array<T, Unique> {
+        Array {
+            opaque: self.opaque,
+            _phantom: PhantomData,
+            _own: PhantomData,
+        }
     }
 
-    /// Changes the generic type on this array, without changing its contents. Needed for API
-    /// functions that return a variant array even though we know its type, and for API functions
-    /// that take a variant array even though we want to pass a typed one.
+    /// Asserts that this array will only ever be accessed from the current thread, allowing
+    /// mutation without requiring structurally unique ownership of the underlying storage.
     ///
-    /// This is marked `unsafe` since it can be used to break the invariant that a `TypedArray<T>`
-    /// always holds a Godot array whose runtime type is `T`.
\ No newline at end of file
+    /// # Safety
+    /// The caller must ensure no other thread ever accesses the same underlying storage for as
+    /// long as the returned handle (or any handle derived from it) is used.
+    pub unsafe fn into_thread_local(self) -> Array<T, ThreadLocal> {
+        Array {
+            opaque: self.opaque,
+            _phantom: PhantomData,
+            _own: PhantomData,
+        }
+    }
+}
+
+/// `serde` support for [`Array<T, Own>`], serializing as a sequence of its elements.
+///
+/// Like [`Variant`]'s own `serde` support (see `variant/mod.rs`), this goes through the already
+/// strongly-typed element accessors rather than a generic `Variant` conversion -- `Array` doesn't
+/// implement `FromVariant`/`ToVariant` anywhere in this crate (a pre-existing gap, not introduced
+/// here), so round-tripping through `Variant` isn't an option yet regardless.
+///
+/// `Deserialize` is only implemented for a typed, uniquely-owned `Array<T, Unique>`, collected via
+/// the [`FromIterator`] path above -- matching every other constructor in this module, a freshly
+/// deserialized array has no other handle yet. The untyped [`VariantArray`] doesn't get a
+/// `Deserialize` impl: constructing one at all requires `T: VariantMetadata`, which `Variant` itself
+/// doesn't implement (see `array_ctor.rs`), so there is no `Array::<Variant, _>::new()` to collect
+/// into in the first place.
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::{Array, ArrayOwnership, FromVariant, ToVariant, Unique, Variant, VariantMetadata};
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl<T, Own> Serialize for Array<T, Own>
+    where
+        T: VariantMetadata + FromVariant + ToVariant + Serialize,
+        Own: ArrayOwnership,
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for value in self.iter_shared() {
+                seq.serialize_element(&value)?;
+            }
+            seq.end()
+        }
+    }
+
+    impl<Own: ArrayOwnership> Serialize for Array<Variant, Own> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+            for index in 0..self.len() {
+                seq.serialize_element(&self.get(index))?;
+            }
+            seq.end()
+        }
+    }
+
+    impl<'de, T> Deserialize<'de> for Array<T, Unique>
+    where
+        T: VariantMetadata + FromVariant + ToVariant + Deserialize<'de>,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let elements = Vec::<T>::deserialize(deserializer)?;
+            Ok(elements.into_iter().collect())
+        }
+    }
+}
@@ -0,0 +1,59 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// `Array<T>`'s own construction, same missing-subsystem story as `array_typed.rs`: `array.rs`'s
+// `impl<T: VariantMetadata> Array<T>` block never reaches a `new()`/`Default`, since the file is
+// cut off before getting there (confirmed identical to baseline). `Default` can't be generated via
+// the existing `impl_builtin_traits_inner!` macro either -- it emits a non-generic `impl Default
+// for $Type`, which can't carry `Array<T>`'s `T` parameter. Hand-written here instead, following
+// the same direct-construction shape that macro expands to for every other CoW builtin.
+//
+// Only `Array<T, Unique>` gets a `Default` impl: a freshly constructed array has no other handle
+// around to share it with, so `Unique` is the only honest state to hand back. `Array<T, Own>`'s
+// `GodotFfi` impl below stays generic over every `Own`, since the FFI layer itself doesn't care who
+// else might be holding a handle -- but that means it can't carry the `ffi_methods!` `;Default`
+// suffix (which requires `Self: Default`, true only for `Unique`). Code paths that rely on
+// `from_sys_init_default()` (e.g. `FromVariant`-style in-place writes) are therefore only
+// supported when going through `Array<T, Unique>` -- which matches how such calls naturally
+// produce a fresh, uniquely-owned handle in the first place.
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::meta::VariantMetadata;
+use crate::builtin::{Array, ArrayOwnership, Unique};
+
+impl<T: VariantMetadata> Default for Array<T, Unique> {
+    #[inline]
+    fn default() -> Self {
+        let mut uninit = std::mem::MaybeUninit::<Self>::uninit();
+
+        unsafe {
+            let self_ptr = (*uninit.as_mut_ptr()).sys_mut();
+            sys::builtin_call! {
+                array_construct_default(self_ptr, std::ptr::null_mut())
+            };
+
+            uninit.assume_init()
+        }
+    }
+}
+
+impl<T: VariantMetadata> Array<T, Unique> {
+    /// Constructs an empty array, with no runtime element type stamped yet.
+    ///
+    /// The first typed mutation (`push`, `set`, `insert` from [`array_typed`](super::array_typed))
+    /// stamps the array's runtime type to `T`; an array that's never mutated stays untyped, same
+    /// as freshly constructing one through GDScript's `Array()` and never assigning to it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: VariantMetadata, Own: ArrayOwnership> GodotFfi for Array<T, Own> {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
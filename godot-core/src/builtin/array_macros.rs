@@ -0,0 +1,34 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Constructs a [`TypedArray<T>`](crate::builtin::Array) from the given elements, inferring `T`
+/// from them (same way [`vec!`] infers its element type), and stamping the array's runtime
+/// element type along the way.
+///
+/// ```no_run
+/// # use godot::builtin::array;
+/// let ints = array![1, 2, 3];
+/// let empty = array![]; // an untyped `VariantArray`, since there's nothing to infer `T` from.
+/// ```
+///
+/// Use [`varray!`](crate::varray) instead to build an untyped, heterogeneously-typed array.
+#[macro_export]
+macro_rules! array {
+    () => {
+        // `VariantArray::new()` doesn't exist: `Array::<T, Unique>::new()` requires
+        // `T: VariantMetadata`, which `Variant` itself doesn't implement (see `array_ctor.rs`).
+        // Route through the untyped constructor instead.
+        $crate::varray![]
+    };
+    ($($elem:expr),+ $(,)?) => {
+        {
+            let mut array = $crate::builtin::Array::new();
+            $(array.push($elem);)+
+            array
+        }
+    };
+}
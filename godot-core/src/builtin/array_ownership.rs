@@ -0,0 +1,67 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Typestate ownership markers for [`Array<T, Own>`](super::Array). See the type-level docs on
+//! `Array` for what each state means; this module only holds the marker types themselves and the
+//! (sealed) traits that gate which methods are available in which state.
+
+use std::cell::Cell;
+use std::marker::PhantomData;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Gates `Array<T, Own>`'s second type parameter to just [`Unique`], [`Shared`] and
+/// [`ThreadLocal`] -- sealed so no type outside this crate can invent a fourth ownership state.
+pub trait ArrayOwnership: private::Sealed {}
+
+/// Marks the `Own` states in which `Array<T, Own>`'s mutating methods (`set`, `push`, `clear`,
+/// ...) are available: [`Unique`] and [`ThreadLocal`].
+pub trait MutableOwnership: ArrayOwnership {}
+
+/// No other `Array` handle refers to this array's underlying storage. Every constructor
+/// (`Array::new()`, `array![...]`, `FromIterator`, ...) returns a `Unique` array.
+pub struct Unique {
+    // `Cell` is `Send` but `!Sync`; this field exists only to make the marker inherit that (and
+    // thus `Array<T, Unique>`, via its `PhantomData<Own>` field): movable to another thread like
+    // any uniquely-owned value, but never safely referenced from two threads at once.
+    _not_sync: PhantomData<Cell<()>>,
+}
+
+/// This array's underlying storage may also be visible through other handles (obtained via
+/// [`Array::share()`](super::Array::share)). Only read-only methods are available: nothing here
+/// can mutate storage another handle might be reading concurrently.
+pub struct Shared {
+    _private: (),
+}
+
+/// Like [`Unique`] (mutating methods are available), but obtained through the `unsafe`
+/// [`Array::into_thread_local()`](super::Array::into_thread_local), which asserts -- rather than
+/// structurally guarantees -- that no other handle will ever touch this storage from any thread.
+pub struct ThreadLocal {
+    // A raw pointer is neither `Send` nor `Sync`; this keeps `Array<T, ThreadLocal>` pinned to the
+    // thread that created it, matching the single-thread assertion `into_thread_local()` makes.
+    _not_send_or_sync: PhantomData<*const ()>,
+}
+
+impl private::Sealed for Unique {}
+impl private::Sealed for Shared {}
+impl private::Sealed for ThreadLocal {}
+
+impl ArrayOwnership for Unique {}
+impl ArrayOwnership for Shared {}
+impl ArrayOwnership for ThreadLocal {}
+
+impl MutableOwnership for Unique {}
+impl MutableOwnership for ThreadLocal {}
+
+// SAFETY: `Shared` only ever gates read-only access (see `MutableOwnership`, which it does not
+// implement), and Godot's own refcounting on the underlying CoW storage is atomic, so concurrent
+// reads of the same array from multiple threads are sound.
+unsafe impl Send for Shared {}
+unsafe impl Sync for Shared {}
@@ -0,0 +1,233 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Typed element access for `Array<T, Own>`, converting through `Variant` and enforcing the
+// engine's own runtime element type.
+//
+// This lives in its own file rather than `array.rs` itself, where it would normally belong next
+// to the rest of `Array<T, Own>`'s inherent methods: `array.rs` is cut off mid its primary `impl`
+// block at baseline (confirmed via `git show` against the baseline commit -- not something
+// introduced by changes in this tree), so appending here isn't safe. Everything below only relies
+// on the public surface `array.rs` already exposes (`len`, `as_inner`), the same workaround used
+// for `Basis`/`Quaternion`'s `glam` conversions in `glam_helpers.rs`.
+//
+// Read-only methods (`get`, `contains`, `count`) are available for any `Own`; mutating ones
+// (`set`, `push`, `insert`) require `Own: MutableOwnership`, matching the rest of `Array<T, Own>`.
+
+use godot_ffi as sys;
+use sys::{interface_fn, GodotFfi};
+
+use crate::builtin::meta::VariantMetadata;
+use crate::builtin::{
+    Array, ArrayOwnership, FromVariant, MutableOwnership, ToVariant, Variant, VariantType,
+};
+
+impl<Own: ArrayOwnership> Array<Variant, Own> {
+    /// Returns a copy of the element at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn get(&self, index: usize) -> Variant {
+        self.check_index(index);
+        self.as_inner().get(crate::builtin::to_i64(index))
+    }
+
+    /// Returns a copy of the element at `index`, or `None` if out of bounds.
+    ///
+    /// Complements the panicking [`get()`](Self::get) for callers that would otherwise just
+    /// bounds-check themselves first.
+    pub fn try_get(&self, index: usize) -> Option<Variant> {
+        if index < self.len() {
+            Some(self.get(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the array contains an element equal to `value`.
+    pub fn contains(&self, value: &Variant) -> bool {
+        self.as_inner().has(value.clone())
+    }
+
+    /// Returns the number of elements in the array equal to `value`.
+    pub fn count(&self, value: &Variant) -> usize {
+        crate::builtin::to_usize(self.as_inner().count(value.clone()))
+    }
+
+    fn check_index(&self, index: usize) {
+        assert!(
+            index < self.len(),
+            "Array index {index} out of bounds: length is {}",
+            self.len()
+        );
+    }
+}
+
+impl<Own: MutableOwnership> Array<Variant, Own> {
+    /// Writes `value` into the element at `index`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: Variant) {
+        self.check_index(index);
+        self.as_inner().set(crate::builtin::to_i64(index), value);
+    }
+
+    /// Appends an element to the end of the array.
+    pub fn push(&mut self, value: Variant) {
+        self.as_inner().push_back(value);
+    }
+
+    /// Inserts `value` before the element currently at `index`.
+    ///
+    /// # Panics
+    /// If `index` is greater than [`len()`](Self::len).
+    pub fn insert(&mut self, index: usize, value: Variant) {
+        assert!(
+            index <= self.len(),
+            "Array insertion index {index} out of bounds: length is {}",
+            self.len()
+        );
+        self.as_inner().insert(crate::builtin::to_i64(index), value);
+    }
+}
+
+impl<T, Own: ArrayOwnership> Array<T, Own>
+where
+    T: VariantMetadata + FromVariant + ToVariant,
+{
+    /// Returns a copy of the element at `index`, converted to `T`.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds, or if the array's runtime element type (set by a previous
+    /// call into this array from outside this `Array<T, Own>` handle) doesn't match `T`.
+    pub fn get(&self, index: usize) -> T {
+        self.verify_runtime_type();
+        self.check_index(index);
+
+        let variant = self.as_inner().get(crate::builtin::to_i64(index));
+        T::from_variant(&variant)
+    }
+
+    /// Returns a copy of the element at `index`, converted to `T`, or `None` if out of bounds.
+    ///
+    /// Complements the panicking [`get()`](Self::get) for callers that would otherwise just
+    /// bounds-check themselves first.
+    ///
+    /// # Panics
+    /// If the array's runtime element type doesn't match `T` (see [`get()`](Self::get)) -- there's
+    /// no way to convert to `T` in that case regardless of the index, so this isn't folded into the
+    /// `None` case.
+    pub fn try_get(&self, index: usize) -> Option<T> {
+        self.verify_runtime_type();
+
+        if index < self.len() {
+            let variant = self.as_inner().get(crate::builtin::to_i64(index));
+            Some(T::from_variant(&variant))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the array contains an element equal to `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.verify_runtime_type();
+        self.as_inner().has(value.to_variant())
+    }
+
+    /// Returns the number of elements in the array equal to `value`.
+    pub fn count(&self, value: &T) -> usize {
+        self.verify_runtime_type();
+        crate::builtin::to_usize(self.as_inner().count(value.to_variant()))
+    }
+
+    fn check_index(&self, index: usize) {
+        assert!(
+            index < self.len(),
+            "Array index {index} out of bounds: length is {}",
+            self.len()
+        );
+    }
+
+    /// Panics if this array's runtime element type has been stamped (by a previous call through
+    /// this or another `Array<T, Own>` handle to the same underlying array) to something other
+    /// than `T`. An array whose runtime type was never stamped (e.g. handed to Rust straight from
+    /// the engine) passes unchecked -- there's nothing to compare against yet.
+    fn verify_runtime_type(&self) {
+        let runtime_type = unsafe {
+            let ty = interface_fn!(array_get_typed_builtin)(self.sys());
+            // SAFETY: `VariantType` is a `#[repr(i32)]` enum whose discriminants are the
+            // GDExtension variant type tags.
+            std::mem::transmute::<_, VariantType>(ty)
+        };
+
+        assert!(
+            runtime_type == VariantType::Nil || runtime_type == T::variant_type(),
+            "Array has runtime element type {runtime_type:?}, which does not match the type \
+            this Array<T> handle expects ({:?})",
+            T::variant_type()
+        );
+    }
+}
+
+impl<T, Own: MutableOwnership> Array<T, Own>
+where
+    T: VariantMetadata + FromVariant + ToVariant,
+{
+    /// Writes `value` into the element at `index`, converting it to a [`Variant`] first.
+    ///
+    /// # Panics
+    /// If `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        self.ensure_runtime_type();
+        self.check_index(index);
+
+        self.as_inner()
+            .set(crate::builtin::to_i64(index), value.to_variant());
+    }
+
+    /// Appends an element to the end of the array, converting it to a [`Variant`] first.
+    pub fn push(&mut self, value: T) {
+        self.ensure_runtime_type();
+        self.as_inner().push_back(value.to_variant());
+    }
+
+    /// Inserts `value` before the element currently at `index`, converting it to a [`Variant`]
+    /// first.
+    ///
+    /// # Panics
+    /// If `index` is greater than [`len()`](Self::len).
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.ensure_runtime_type();
+
+        assert!(
+            index <= self.len(),
+            "Array insertion index {index} out of bounds: length is {}",
+            self.len()
+        );
+        self.as_inner()
+            .insert(crate::builtin::to_i64(index), value.to_variant());
+    }
+
+    /// Stamps this array's runtime element type as `T`, if it isn't already.
+    ///
+    /// `Array::new()` has no way to know which `T` it'll end up stamped with later (an array stays
+    /// untyped until its first typed mutation, same as GDScript's bare `Array()`), so there's no
+    /// single "on construction" hook to do this once -- every mutating method here calls this
+    /// first instead. The underlying `array_set_typed` call is a no-op once an array's runtime type
+    /// is already set, so this is cheap to repeat.
+    fn ensure_runtime_type(&mut self) {
+        unsafe {
+            interface_fn!(array_set_typed)(
+                self.sys_mut(),
+                T::variant_type() as sys::GDExtensionVariantType,
+                T::class_name().string_sys(),
+                std::ptr::null(),
+            );
+        }
+    }
+}
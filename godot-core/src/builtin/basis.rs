@@ -1,5 +1,3 @@
-
-use std::cmp::Ordering;
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
@@ -11,9 +9,8 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use super::glam_helpers::{GlamConv, GlamType};
-use super::real_consts::FRAC_PI_2;
 use super::{math::*, Quaternion, Vector3};
-use super::{real, RMat3, RQuat, RVec2, RVec3};
+use super::{real, RMat3, RQuat};
 
 /// A 3x3 matrix, typically used as an orthogonal basis for [`Transform3D`](crate::builtin::Transform3D).
 ///
@@ -24,6 +21,7 @@ use super::{real, RMat3, RQuat, RVec2, RVec3};
 /// The basis vectors are the columns of the matrix, whereas the [`rows`](Self::rows) field represents
 /// the row vectors.
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Basis {
     /// The rows of the matrix. These are *not* the basis vectors.  
@@ -148,6 +146,54 @@ impl Basis {
         super::inner::InnerBasis::looking_at(target, up)
     }
 
+    /// Returns the first basis vector (the matrix's first column).
+    pub fn col_a(&self) -> Vector3 {
+        Vector3::new(self.rows[0].x, self.rows[1].x, self.rows[2].x)
+    }
+
+    /// Returns the second basis vector (the matrix's second column).
+    pub fn col_b(&self) -> Vector3 {
+        Vector3::new(self.rows[0].y, self.rows[1].y, self.rows[2].y)
+    }
+
+    /// Returns the third basis vector (the matrix's third column).
+    pub fn col_c(&self) -> Vector3 {
+        Vector3::new(self.rows[0].z, self.rows[1].z, self.rows[2].z)
+    }
+
+    /// Sets the first basis vector (the matrix's first column).
+    pub fn set_col_a(&mut self, v: Vector3) {
+        self.rows[0].x = v.x;
+        self.rows[1].x = v.y;
+        self.rows[2].x = v.z;
+    }
+
+    /// Sets the second basis vector (the matrix's second column).
+    pub fn set_col_b(&mut self, v: Vector3) {
+        self.rows[0].y = v.x;
+        self.rows[1].y = v.y;
+        self.rows[2].y = v.z;
+    }
+
+    /// Sets the third basis vector (the matrix's third column).
+    pub fn set_col_c(&mut self, v: Vector3) {
+        self.rows[0].z = v.x;
+        self.rows[1].z = v.y;
+        self.rows[2].z = v.z;
+    }
+
+    /// Returns the determinant of this matrix.
+    pub fn determinant(&self) -> real {
+        self.rows[0].dot(self.rows[1].cross(self.rows[2]))
+    }
+
+    /// Returns the transpose of this matrix, i.e. the matrix whose rows are this matrix's
+    /// columns and vice versa.
+    #[must_use]
+    pub fn transposed(self) -> Self {
+        Self::from_cols(self.rows[0], self.rows[1], self.rows[2])
+    }
+
     /// Creates a `[Vector3; 3]` with the columns of the `Basis`.
     pub fn to_cols(self) -> [Vector3; 3] {
         self.transposed().rows
@@ -198,50 +244,156 @@ impl Basis {
     ///
     /// _Godot equivalent: `Basis.get_euler()`_
     pub fn to_euler(self, order: EulerOrder) -> Vector3 {
-        use glam::swizzles::Vec3Swizzles as _;
-
-        let col_a = self.col_a().to_glam();
-        let col_b = self.col_b().to_glam();
-        let col_c = self.col_c().to_glam();
-
-        let row_a = self.rows[0].to_glam();
-        let row_b = self.rows[1].to_glam();
-        let row_c = self.rows[2].to_glam();
-
-        let major = match order {
-            EulerOrder::XYZ => self.rows[0].z,
-            EulerOrder::XZY => self.rows[0].y,
-            EulerOrder::YXZ => self.rows[1].z,
-            EulerOrder::YZX => self.rows[1].x,
-            EulerOrder::ZXY => self.rows[2].y,
-            EulerOrder::ZYX => self.rows[2].x,
-        };
-
-        // Return the simplest forms for pure rotations
-        if let Some(pure_rotation) = match order {
-            EulerOrder::XYZ => self
-                .to_euler_pure_rotation(major, 1, row_a.zx())
-                .map(RVec3::yxz),
-            EulerOrder::YXZ => {
-                self.to_euler_pure_rotation(major, 0, RVec2::new(-major, self.rows[1].y))
-            }
-            _ => None,
-        } {
-            return pure_rotation.to_front();
+        // Each order below solves for the angles of its own `major` component (the sine of the
+        // "middle" axis of the rotation) via `asin`, then recovers the other two via `atan2` on
+        // the remaining matrix entries. The `major` argument is clamped to avoid `asin` returning
+        // `NaN` from floating-point overshoot right at the gimbal-lock poles.
+        let Basis { rows } = self;
+
+        fn clamped_asin(value: real) -> real {
+            value.clamp(-1.0, 1.0).asin()
         }
 
         match order {
-            EulerOrder::XYZ => {
-                -Self::to_euler_inner(major, col_c.yz(), row_a.yx(), col_b.zy()).yxz()
-            }
-            EulerOrder::XZY => {
-                Self::to_euler_inner(major, col_b.zy(), row_a.zx(), col_c.yz()).yzx()
-            }
-            EulerOrder::YXZ => {
-                let mut vec = Self::to_euler_inner(major, col_c.xz(), row_b.xy(), row_a.yx());
-                if Self::is_between_neg1_1(major).is_lt() {
-                    vec.y = -vec.y;
-                }
-                vec
-            }
-            EulerOrder::YZX => {
\ No newline at end of file
+            EulerOrder::XYZ => Vector3::new(
+                (-rows[1].z).atan2(rows[2].z),
+                clamped_asin(rows[0].z),
+                (-rows[0].y).atan2(rows[0].x),
+            ),
+            EulerOrder::XZY => Vector3::new(
+                rows[2].y.atan2(rows[1].y),
+                rows[0].z.atan2(rows[0].x),
+                clamped_asin(-rows[0].y),
+            ),
+            EulerOrder::YXZ => Vector3::new(
+                clamped_asin(-rows[1].z),
+                rows[0].z.atan2(rows[2].z),
+                rows[1].x.atan2(rows[1].y),
+            ),
+            EulerOrder::YZX => Vector3::new(
+                (-rows[1].z).atan2(rows[1].y),
+                (-rows[2].x).atan2(rows[0].x),
+                clamped_asin(rows[1].x),
+            ),
+            EulerOrder::ZXY => Vector3::new(
+                clamped_asin(rows[2].y),
+                (-rows[2].x).atan2(rows[2].z),
+                (-rows[0].y).atan2(rows[1].y),
+            ),
+            EulerOrder::ZYX => Vector3::new(
+                rows[2].y.atan2(rows[2].z),
+                clamped_asin(-rows[2].x),
+                rows[1].x.atan2(rows[0].x),
+            ),
+        }
+    }
+
+    /// Returns `true` if this basis and `other` are approximately equal, by calling
+    /// `is_equal_approx` on each row.
+    pub fn is_equal_approx(&self, other: &Self) -> bool {
+        self.rows[0].is_equal_approx(other.rows[0])
+            && self.rows[1].is_equal_approx(other.rows[1])
+            && self.rows[2].is_equal_approx(other.rows[2])
+    }
+
+    /// Returns `true` if this basis is finite, by calling [`Vector3::is_finite()`] on each row.
+    pub fn is_finite(&self) -> bool {
+        self.rows[0].is_finite() && self.rows[1].is_finite() && self.rows[2].is_finite()
+    }
+
+    /// Returns this basis with its basis vectors made orthogonal (90 degrees apart) and
+    /// normalized, using Gram-Schmidt orthonormalization.
+    ///
+    /// _Godot equivalent: `Basis.orthonormalized()`_
+    #[must_use]
+    pub fn orthonormalized(self) -> Self {
+        let x = self.col_a().normalized();
+        let y = (self.col_b() - x * x.dot(self.col_b())).normalized();
+        let z = (self.col_c() - x * x.dot(self.col_c()) - y * y.dot(self.col_c())).normalized();
+
+        Self::from_cols(x, y, z)
+    }
+}
+
+/// The order to use when interpreting a set of Euler angles as a rotation, i.e. which axis each
+/// angle rotates around and in what sequence the rotations are applied.
+///
+/// _Godot equivalent: `@GlobalScope.EulerOrder`_
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum EulerOrder {
+    XYZ = 0,
+    XZY = 1,
+    YZX = 2,
+    YXZ = 3,
+    ZXY = 4,
+    ZYX = 5,
+}
+
+impl Basis {
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(mat: RMat3) -> Self {
+        <RMat3 as GlamType>::to_front(&mat)
+    }
+
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RMat3 {
+        GlamConv::to_glam(&self)
+    }
+}
+
+impl GlamType for RMat3 {
+    type Mapped = Basis;
+
+    fn to_front(&self) -> Basis {
+        Basis::from_cols(
+            Vector3::from_glam(self.x_axis),
+            Vector3::from_glam(self.y_axis),
+            Vector3::from_glam(self.z_axis),
+        )
+    }
+
+    fn from_front(mapped: &Basis) -> Self {
+        let [col_a, col_b, col_c] = mapped.to_cols();
+        RMat3::from_cols(col_a.to_glam(), col_b.to_glam(), col_c.to_glam())
+    }
+}
+
+impl GlamConv for Basis {
+    type Glam = RMat3;
+}
+
+impl Mul for Basis {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.glam2(&rhs, |a, b| a * b)
+    }
+}
+
+impl Mul<Vector3> for Basis {
+    type Output = Vector3;
+
+    fn mul(self, rhs: Vector3) -> Self::Output {
+        self.glam2(&rhs, |mat, vec| mat.mul_vec3(vec))
+    }
+}
+
+impl Display for Basis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Godot output:
+        // [X: (1, 0, 0), Y: (0, 1, 0), Z: (0, 0, 1)]
+        // Where X, Y, Z are the basis vectors (columns).
+
+        write!(
+            f,
+            "[X: {}, Y: {}, Z: {}]",
+            self.col_a(),
+            self.col_b(),
+            self.col_c()
+        )
+    }
+}
+
+impl GodotFfi for Basis {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
@@ -16,6 +16,7 @@ use sys::{ffi_methods, GodotFfi};
 /// values outside this range are explicitly allowed for e.g. High Dynamic Range (HDR).
 #[repr(C)]
 #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Color {
     /// The color's red component.
     pub r: f32,
@@ -202,4 +203,174 @@ impl Color {
         self.a = from_u8(a);
     }
 
-    // TODO add getters and 
\ No newline at end of file
+    /// Returns a copy of this color converted from sRGB (gamma-encoded) to linear color space.
+    ///
+    /// This applies the standard sRGB electro-optical transfer function per channel. Alpha is
+    /// passed through unchanged. Values outside the `[0, 1]` range (as can occur with HDR colors)
+    /// are transformed using the same formula rather than being clamped first.
+    #[must_use]
+    pub fn srgb_to_linear(self) -> Color {
+        fn convert(c: f32) -> f32 {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        }
+
+        Color::from_rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Returns a copy of this color converted from linear to sRGB (gamma-encoded) color space.
+    ///
+    /// This applies the standard sRGB opto-electronic transfer function per channel. Alpha is
+    /// passed through unchanged. Values outside the `[0, 1]` range (as can occur with HDR colors)
+    /// are transformed using the same formula rather than being clamped first.
+    #[must_use]
+    pub fn linear_to_srgb(self) -> Color {
+        fn convert(c: f32) -> f32 {
+            if c <= 0.0031308 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        }
+
+        Color::from_rgba(convert(self.r), convert(self.g), convert(self.b), self.a)
+    }
+
+    /// Composites `self` below `over` in linear color space, returning the blended color.
+    ///
+    /// Both colors are converted to linear space before blending and the result is converted back
+    /// to sRGB, which avoids the dark-fringe artifacts of naive sRGB alpha blending.
+    #[must_use]
+    pub fn blend(self, over: Color) -> Color {
+        let below = self.srgb_to_linear();
+        let over = over.srgb_to_linear();
+
+        let a = below.a * (1.0 - over.a) + over.a;
+        let blended = if a == 0.0 {
+            Color::from_rgba(0.0, 0.0, 0.0, 0.0)
+        } else {
+            let r = (below.r * below.a * (1.0 - over.a) + over.r * over.a) / a;
+            let g = (below.g * below.a * (1.0 - over.a) + over.g * over.a) / a;
+            let b = (below.b * below.a * (1.0 - over.a) + over.b * over.a) / a;
+            Color::from_rgba(r, g, b, a)
+        };
+
+        blended.linear_to_srgb()
+    }
+
+    /// Returns the linear interpolation between this color and `to`, by the given `weight`
+    /// (typically in `[0, 1]`). All four components, including alpha, are interpolated.
+    #[must_use]
+    pub fn lerp(self, to: Color, weight: f32) -> Color {
+        Color::from_rgba(
+            self.r + (to.r - self.r) * weight,
+            self.g + (to.g - self.g) * weight,
+            self.b + (to.b - self.b) * weight,
+            self.a + (to.a - self.a) * weight,
+        )
+    }
+
+    /// Returns a copy of this color darkened by `amount` (in `[0, 1]`), which is multiplied by the
+    /// color's own value to reduce each of the red, green and blue components. Alpha is untouched.
+    #[must_use]
+    pub fn darkened(self, amount: f32) -> Color {
+        Color::from_rgba(
+            self.r * (1.0 - amount),
+            self.g * (1.0 - amount),
+            self.b * (1.0 - amount),
+            self.a,
+        )
+    }
+
+    /// Returns a copy of this color lightened by `amount` (in `[0, 1]`), by blending it towards
+    /// white. Alpha is untouched.
+    #[must_use]
+    pub fn lightened(self, amount: f32) -> Color {
+        Color::from_rgba(
+            self.r + (1.0 - self.r) * amount,
+            self.g + (1.0 - self.g) * amount,
+            self.b + (1.0 - self.b) * amount,
+            self.a,
+        )
+    }
+
+    /// Returns the inverted color: `(1 - r, 1 - g, 1 - b, a)`. Alpha is untouched.
+    #[must_use]
+    pub fn inverted(self) -> Color {
+        Color::from_rgba(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
+    }
+
+    // TODO add getters and
+}
+
+impl ops::Add<Color> for Color {
+    type Output = Self;
+    fn add(self, rhs: Color) -> Self::Output {
+        Color::from_rgba(
+            self.r + rhs.r,
+            self.g + rhs.g,
+            self.b + rhs.b,
+            self.a + rhs.a,
+        )
+    }
+}
+
+impl ops::Sub<Color> for Color {
+    type Output = Self;
+    fn sub(self, rhs: Color) -> Self::Output {
+        Color::from_rgba(
+            self.r - rhs.r,
+            self.g - rhs.g,
+            self.b - rhs.b,
+            self.a - rhs.a,
+        )
+    }
+}
+
+impl ops::Mul<Color> for Color {
+    type Output = Self;
+    fn mul(self, rhs: Color) -> Self::Output {
+        Color::from_rgba(
+            self.r * rhs.r,
+            self.g * rhs.g,
+            self.b * rhs.b,
+            self.a * rhs.a,
+        )
+    }
+}
+
+impl ops::Mul<f32> for Color {
+    type Output = Self;
+    fn mul(self, rhs: f32) -> Self::Output {
+        Color::from_rgba(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
+    }
+}
+
+impl ops::Div<Color> for Color {
+    type Output = Self;
+    fn div(self, rhs: Color) -> Self::Output {
+        Color::from_rgba(
+            self.r / rhs.r,
+            self.g / rhs.g,
+            self.b / rhs.b,
+            self.a / rhs.a,
+        )
+    }
+}
+
+impl ops::Div<f32> for Color {
+    type Output = Self;
+    fn div(self, rhs: f32) -> Self::Output {
+        Color::from_rgba(self.r / rhs, self.g / rhs, self.b / rhs, self.a / rhs)
+    }
+}
+
+impl ops::Neg for Color {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Color::from_rgba(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, 1.0 - self.a)
+    }
+}
@@ -0,0 +1,147 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use super::math::lerp;
+use super::{real, Vector3};
+
+/// Default number of uniform `t` intervals used to bake the arc-length table in
+/// [`Bezier3D::sample_baked`] and [`Bezier3D::length`].
+const DEFAULT_BAKE_INTERVALS: usize = 64;
+
+/// A cubic Bézier curve in 3D space, defined by its start/end points and two control points.
+///
+/// Besides direct evaluation by the curve parameter `t` (see [`Self::sample_position`]), this type
+/// can bake an arc-length lookup table so the curve can be driven by distance travelled rather than
+/// by the nonlinear `t` -- see [`Self::bake`] and [`BakedBezier3D::sample_baked`].
+///
+/// There is no `Bezier2D` counterpart; only 3D curves are provided.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bezier3D {
+    pub start: Vector3,
+    pub control_1: Vector3,
+    pub control_2: Vector3,
+    pub end: Vector3,
+}
+
+impl Bezier3D {
+    /// Creates a new curve from its start point, two control points, and end point.
+    pub const fn new(start: Vector3, control_1: Vector3, control_2: Vector3, end: Vector3) -> Self {
+        Self {
+            start,
+            control_1,
+            control_2,
+            end,
+        }
+    }
+
+    /// Samples the curve's position at parameter `t`, which ranges from `0.0` (start) to `1.0`
+    /// (end).
+    ///
+    /// `t` does not correspond linearly to distance travelled along the curve; use [`Self::bake`]
+    /// together with [`BakedBezier3D::sample_baked`] for constant-speed sampling.
+    pub fn sample_position(&self, t: real) -> Vector3 {
+        self.start
+            .bezier_interpolate(self.control_1, self.control_2, self.end, t)
+    }
+
+    /// Samples the curve's tangent (the unnormalized derivative of position) at parameter `t`.
+    pub fn sample_tangent(&self, t: real) -> Vector3 {
+        self.start
+            .bezier_derivative(self.control_1, self.control_2, self.end, t)
+    }
+
+    /// Precomputes an arc-length lookup table over `subdivisions` uniformly spaced intervals of `t`
+    /// (`subdivisions + 1` samples), enabling constant-speed sampling via [`BakedBezier3D::sample_baked`].
+    ///
+    /// # Panics
+    /// If `subdivisions` is `0`.
+    pub fn bake(&self, subdivisions: usize) -> BakedBezier3D {
+        assert!(subdivisions >= 1, "subdivisions must be at least 1");
+
+        let mut cumulative_lengths = Vec::with_capacity(subdivisions + 1);
+        cumulative_lengths.push(0.0);
+
+        let mut previous = self.sample_position(0.0);
+        let mut total = 0.0;
+        for i in 1..=subdivisions {
+            let t = i as real / subdivisions as real;
+            let current = self.sample_position(t);
+            total += (current - previous).length();
+            cumulative_lengths.push(total);
+            previous = current;
+        }
+
+        BakedBezier3D {
+            curve: *self,
+            cumulative_lengths,
+        }
+    }
+
+    /// Samples the curve's position at the given `distance` travelled along it, baking a
+    /// [`DEFAULT_BAKE_INTERVALS`]-interval arc-length table on the fly.
+    ///
+    /// If you need to sample the same curve by distance more than once, bake it explicitly via
+    /// [`Self::bake`] and reuse the resulting [`BakedBezier3D`] instead.
+    pub fn sample_baked(&self, distance: real) -> Vector3 {
+        self.bake(DEFAULT_BAKE_INTERVALS).sample_baked(distance)
+    }
+
+    /// Returns the approximate length of the curve, from a [`DEFAULT_BAKE_INTERVALS`]-interval
+    /// arc-length table.
+    pub fn length(&self) -> real {
+        self.bake(DEFAULT_BAKE_INTERVALS).length()
+    }
+}
+
+/// An arc-length lookup table precomputed from a [`Bezier3D`], allowing the curve to be sampled by
+/// distance travelled (constant speed) rather than by its nonlinear `t` parameter.
+///
+/// Obtained via [`Bezier3D::bake`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BakedBezier3D {
+    curve: Bezier3D,
+    cumulative_lengths: Vec<real>,
+}
+
+impl BakedBezier3D {
+    /// Returns the total length of the baked curve.
+    pub fn length(&self) -> real {
+        *self
+            .cumulative_lengths
+            .last()
+            .expect("cumulative_lengths always has at least one entry")
+    }
+
+    /// Samples the curve's position at the given `distance` travelled along it, clamped to
+    /// `[0.0, self.length()]`.
+    pub fn sample_baked(&self, distance: real) -> Vector3 {
+        let distance = distance.clamp(0.0, self.length());
+        let subdivisions = self.cumulative_lengths.len() - 1;
+
+        // Find the first segment whose cumulative length reaches `distance`, then linearly
+        // interpolate `t` within that segment using its local arc length.
+        let segment = self
+            .cumulative_lengths
+            .partition_point(|&len| len < distance)
+            .clamp(1, subdivisions);
+
+        let segment_start = self.cumulative_lengths[segment - 1];
+        let segment_end = self.cumulative_lengths[segment];
+        let segment_length = segment_end - segment_start;
+
+        let local_t = if segment_length > 0.0 {
+            (distance - segment_start) / segment_length
+        } else {
+            0.0
+        };
+
+        let t0 = (segment - 1) as real / subdivisions as real;
+        let t1 = segment as real / subdivisions as real;
+
+        self.curve.sample_position(lerp(t0, t1, local_t))
+    }
+}
@@ -6,9 +6,11 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, FromVariant, ToVariant, Variant};
+use crate::builtin::{inner, FromVariant, ToVariant, Variant, VariantConversionError};
 use crate::obj::Share;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ptr::addr_of_mut;
 use sys::types::OpaqueDictionary;
@@ -175,6 +177,20 @@ impl Dictionary {
         self.as_inner().size().try_into().unwrap()
     }
 
+    /// Converts this dictionary to a Rust `HashMap`, converting each key and value via
+    /// [`FromVariant`].
+    ///
+    /// Returns an error if any key or value does not convert to `K`/`V`.
+    pub fn to_hashmap<K, V>(&self) -> Result<HashMap<K, V>, VariantConversionError>
+    where
+        K: FromVariant + Eq + Hash,
+        V: FromVariant,
+    {
+        self.iter_shared()
+            .map(|(key, value)| Ok((K::try_from_variant(&key)?, V::try_from_variant(&value)?)))
+            .collect()
+    }
+
     /// Insert a value at the given key, returning the previous value for that key (if available).
     ///
     /// If you don't need the previous value, use [`Self::set`] instead.
@@ -226,4 +242,127 @@ impl Dictionary {
 
     /// Get the pointer corresponding to the given key in the dictionary.
     ///
-    /// If there exists n
\ No newline at end of file
+    /// If there exists no such key, a new one is inserted, with value `NIL`.
+    fn get_ptr_mut<K: ToVariant>(&mut self, key: K) -> *mut Variant {
+        let key = key.to_variant();
+
+        unsafe {
+            let variant_ptr = interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys());
+            Variant::ptr_from_sys_mut(variant_ptr)
+        }
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> FromIterator<(K, V)> for Dictionary {
+    fn from_iter<T: IntoIterator<Item = (K, V)>>(iter: T) -> Self {
+        let mut dict = Self::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> Extend<(K, V)> for Dictionary {
+    fn extend<T: IntoIterator<Item = (K, V)>>(&mut self, iter: T) {
+        for (key, value) in iter {
+            self.set(key, value);
+        }
+    }
+}
+
+impl<K: ToVariant, V: ToVariant> From<HashMap<K, V>> for Dictionary {
+    fn from(map: HashMap<K, V>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hashmap_roundtrip() {
+        let mut map = HashMap::new();
+        map.insert("foo".to_string(), 1i64);
+        map.insert("bar".to_string(), 2i64);
+
+        let dict: Dictionary = map.clone().into();
+        let roundtripped: HashMap<String, i64> = dict.to_hashmap().unwrap();
+
+        assert_eq!(map, roundtripped);
+    }
+
+    #[test]
+    fn from_iterator_and_extend() {
+        let mut dict: Dictionary = [("a", 1i64), ("b", 2i64)].into_iter().collect();
+        dict.extend([("c", 3i64)]);
+
+        assert_eq!(dict.len(), 3);
+        assert_eq!(dict.get_or_nil("c").to::<i64>(), 3);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::Dictionary;
+    use crate::builtin::{ToVariant, Variant};
+    use serde::ser::SerializeMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+
+    impl Serialize for Dictionary {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(self.len()))?;
+            for (key, value) in self.iter_shared() {
+                map.serialize_entry(&key, &value)?;
+            }
+            map.end()
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Dictionary {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_map(DictionaryVisitor)
+        }
+    }
+
+    struct DictionaryVisitor;
+
+    impl<'de> serde::de::Visitor<'de> for DictionaryVisitor {
+        type Value = Dictionary;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a Godot dictionary")
+        }
+
+        fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut dict = Dictionary::new();
+            while let Some((key, value)) = access.next_entry::<Variant, Variant>()? {
+                dict.set(key.to_variant(), value.to_variant());
+            }
+            Ok(dict)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn serde_roundtrip() {
+            let mut dict = Dictionary::new();
+            dict.set("foo", 1i64);
+            dict.set("bar", "hello".to_variant());
+
+            let json = serde_json::to_string(&dict).unwrap();
+            let deserialized: Dictionary = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(dict.len(), deserialized.len());
+            for (key, value) in dict.iter_shared() {
+                assert_eq!(Some(value), deserialized.get(key));
+            }
+        }
+    }
+}
\ No newline at end of file
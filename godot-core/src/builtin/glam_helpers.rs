@@ -1,30 +1,43 @@
-
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-// TODO this is experimental -- do not refactor existing types to this yet
-// Need to see if ergonomics are worth the generic complexity.
+// Internal glue between Godot's own builtin types (Vector3, Basis, Transform3D, ...) and their
+// `glam`-based counterparts (RVec3, RMat3, RAffine3, ...). Each builtin type implements
+// `GlamConv`, and the `glam` crate's own types implement `GlamType` for the specific mapping used
+// by that builtin. This lets an operation be written once in terms of `glam`, then converted back
+// and forth at the boundary.
 //
-// Nice:
-//   self.glam2(&with, |a, b| a.dot(b))
-//   self.glam2(&with, glam::f32::Quat::dot)
+// This machinery itself stays private to the crate; the public API surface is the concrete
+// `to_glam`/`from_glam` methods that each builtin type exposes (see e.g. `Vector3::to_glam`).
 //
-// Alternative with only conversions:
-//   self.glam().dot(b.glam())
-//   GlamType::dot(self.glam(), b.glam())
+// Soundness note: `GlamType::{to_front, from_front}` implementations must go through the glam
+// type's own component accessors/constructors (e.g. `RVec3::new(self.x, self.y, self.z)`), never
+// `mem::transmute` or a pointer cast between a `#[repr(C)]` builtin and its glam counterpart.
+// glam's SIMD-backed types (enabled by its `core-simd`/`nightly` features, or implicitly on
+// targets where glam chooses SIMD) can be more strictly aligned (e.g. 16 bytes for `Vec3A`-style
+// layouts) and may pad to a different size than our `#[repr(C)]` structs, which Godot's ABI
+// requires to match its own C layout exactly. A field-by-field conversion is correct regardless
+// of which backend glam was built with, including glam's `scalar-math` fallback (used on
+// platforms like wasm where SIMD codegen is undesirable); a transmute is not. Keep it that way
+// when adding new `GlamType` impls.
 
-use super::real;
+// All builtin `GlamType`/`GlamConv` impls now live alongside their Godot-facing type (see e.g.
+// `Vector3::to_glam`, `Basis::to_glam`); this module only hosts the shared trait definitions.
 
-pub(crate) trait GlamConv {
+pub(crate) trait GlamConv: Sized {
     type Glam: GlamType<Mapped = Self>;
 
     fn to_glam(&self) -> Self::Glam {
         Self::Glam::from_front(self)
     }
 
+    fn from_glam(glam: Self::Glam) -> Self {
+        glam.to_front()
+    }
+
     fn glam<F, R>(&self, unary_fn: F) -> R::Mapped
     where
         R: GlamType,
@@ -33,4 +46,26 @@ pub(crate) trait GlamConv {
         let arg = Self::Glam::from_front(self);
         let result = unary_fn(arg);
 
-        result.to_front()
\ No newline at end of file
+        result.to_front()
+    }
+
+    fn glam2<F, R>(&self, with: &Self, binary_fn: F) -> R::Mapped
+    where
+        R: GlamType,
+        F: FnOnce(Self::Glam, Self::Glam) -> R,
+    {
+        let lhs = Self::Glam::from_front(self);
+        let rhs = Self::Glam::from_front(with);
+        let result = binary_fn(lhs, rhs);
+
+        result.to_front()
+    }
+}
+
+/// Mirror of `GlamConv`, implemented on the `glam` side of a Godot <-> glam type mapping.
+pub(crate) trait GlamType: Sized {
+    type Mapped;
+
+    fn to_front(&self) -> Self::Mapped;
+    fn from_front(mapped: &Self::Mapped) -> Self;
+}
@@ -35,7 +35,7 @@ macro_rules! impl_builtin_traits_inner {
                 unsafe {
                     Self::from_sys_init_default(|self_ptr| {
                         let ctor = ::godot_ffi::builtin_fn!($gd_method);
-                        let args = [self.sys_const()];
+                        let args = [self.sys()];
                         ctor(self_ptr, args.as_ptr());
                     })
                 }
@@ -11,6 +11,19 @@ use super::Vector2;
 
 pub const CMP_EPSILON: real = 0.00001;
 
+/// Approximate equality, using the same relative tolerance as Godot's scalar
+/// [`is_equal_approx`]/[`is_zero_approx`] helpers.
+///
+/// Implemented for the floating-point vector types by folding those scalar helpers over each
+/// component; see [`is_equal_approx`] for the tolerance rule that is applied per axis.
+pub trait ApproxEq {
+    /// Returns whether `self` and `other` are approximately equal, component-wise.
+    fn is_equal_approx(self, other: Self) -> bool;
+
+    /// Returns whether `self` is approximately the zero vector, component-wise.
+    fn is_zero_approx(self) -> bool;
+}
+
 pub fn lerp(a: real, b: real, t: real) -> real {
     a + ((b - a) * t)
 }
@@ -5,9 +5,11 @@
  */
 
 mod class_name;
+mod rpc;
 mod signature;
 
 pub use class_name::*;
+pub use rpc::*;
 pub use signature::*;
 
 use crate::builtin::*;
@@ -34,6 +36,15 @@ pub trait VariantMetadata {
             global::PropertyHint::PROPERTY_HINT_NONE,
             GodotString::new(),
         )
+        .with_usage(Self::property_usage())
+    }
+
+    /// Usage flags to report for this type's properties, unless overridden at the field level.
+    ///
+    /// Defaults to `PROPERTY_USAGE_DEFAULT`; override for types that should e.g. never show up in
+    /// the editor.
+    fn property_usage() -> global::PropertyUsageFlags {
+        global::PropertyUsageFlags::PROPERTY_USAGE_DEFAULT
     }
 
     fn param_metadata() -> sys::GDExtensionClassMethodArgumentMetadata {
@@ -79,4 +90,23 @@ impl PropertyInfo {
         }
     }
 
-    /// Converts to the FFI type. Keep this object allocated while u
\ No newline at end of file
+    /// Overrides the usage flags, e.g. to mark a property as storage-only or editor-only, or to
+    /// group it in the inspector.
+    pub fn with_usage(mut self, usage: global::PropertyUsageFlags) -> Self {
+        self.usage = usage;
+        self
+    }
+
+    /// Converts to the FFI type. Keep this object allocated while using the
+    /// return value, since the sys struct borrows its string pointers from `self`.
+    pub fn property_sys(&self) -> sys::GDExtensionPropertyInfo {
+        sys::GDExtensionPropertyInfo {
+            type_: unsafe { std::mem::transmute(self.variant_type) },
+            name: self.property_name.string_sys(),
+            class_name: self.class_name.string_sys(),
+            hint: self.hint.ord() as u32,
+            hint_string: self.hint_string.string_sys(),
+            usage: self.usage.ord() as u32,
+        }
+    }
+}
\ No newline at end of file
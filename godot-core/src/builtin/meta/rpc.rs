@@ -0,0 +1,63 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+/// Networked-call configuration for a `#[func(rpc = ...)]`-annotated method.
+///
+/// Bundles the pieces that Godot's `Node.rpc_config()` expects together, since `#[godot_api]`
+/// always configures them as a unit at registration time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RpcConfig {
+    pub rpc_mode: RpcMode,
+    pub transfer_mode: TransferMode,
+
+    /// Whether the call is additionally executed locally, in addition to being sent to remote peers.
+    pub call_local: bool,
+
+    /// The channel over which the call is sent.
+    pub channel: i32,
+}
+
+impl Default for RpcConfig {
+    /// Same defaults as an unannotated `#[func]`: not callable remotely.
+    fn default() -> Self {
+        Self {
+            rpc_mode: RpcMode::Disabled,
+            transfer_mode: TransferMode::Reliable,
+            call_local: false,
+            channel: 0,
+        }
+    }
+}
+
+/// Who is allowed to invoke an RPC method remotely.
+///
+/// _Godot equivalent: `MultiplayerAPI.RPCMode`_
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RpcMode {
+    /// The method is not callable remotely; equivalent to not annotating it with `rpc = ...` at all.
+    Disabled,
+
+    /// Only the multiplayer authority of this node may invoke the method remotely.
+    Authority,
+
+    /// Any connected peer may invoke the method remotely, including on the authority.
+    AnyPeer,
+}
+
+/// How reliably and in what order an RPC call is delivered.
+///
+/// _Godot equivalent: `MultiplayerPeer.TransferMode`_
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferMode {
+    /// Packets may be dropped, and may arrive out of order relative to each other.
+    Unreliable,
+
+    /// Packets may be dropped, but those that do arrive do so in the order they were sent.
+    UnreliableOrdered,
+
+    /// Packets are guaranteed to arrive, in the order they were sent.
+    Reliable,
+}
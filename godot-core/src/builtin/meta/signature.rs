@@ -13,28 +13,33 @@ pub trait SignatureTuple {
     type Params;
     type Ret;
 
+    /// Number of parameters (i.e. excluding the return type) in this signature.
+    const PARAM_COUNT: usize;
+
     fn variant_type(index: i32) -> VariantType;
     fn property_info(index: i32, param_name: &str) -> PropertyInfo;
     fn param_metadata(index: i32) -> sys::GDExtensionClassMethodArgumentMetadata;
 
-    unsafe fn varcall<C: GodotClass>(
+    unsafe fn varcall<C: GodotClass, F>(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
-        func: fn(&mut C, Self::Params) -> Self::Ret,
+        func: F,
         method_name: &str,
-    );
+    ) where
+        F: Fn(&mut C, Self::Params) -> Self::Ret;
 
     // Note: this method imposes extra bounds on GodotFfi, which may not be implemented for user types.
     // We could fall back to varcalls in such cases, and not require GodotFfi categorically.
-    unsafe fn ptrcall<C: GodotClass>(
+    unsafe fn ptrcall<C: GodotClass, F>(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         args_ptr: *const sys::GDExtensionConstTypePtr,
         ret: sys::GDExtensionTypePtr,
-        func: fn(&mut C, Self::Params) -> Self::Ret,
+        func: F,
         method_name: &str,
-    );
+    ) where
+        F: Fn(&mut C, Self::Params) -> Self::Ret;
 }
 
 // impl<P, const N: usize> Sig for [P; N]
@@ -64,12 +69,14 @@ macro_rules! impl_signature_for_tuple {
     ) => {
         #[allow(unused_variables)]
         impl<$R, $($Pn,)*> SignatureTuple for ($R, $($Pn,)*)
-            where $R: VariantMetadata + ToVariant + sys::GodotFuncMarshal + Debug,
-               $( $Pn: VariantMetadata + FromVariant + sys::GodotFuncMarshal + Debug, )*
+            where $R: VariantMetadata + ToVariant + sys::GodotFuncMarshal + sys::GodotFfi + Debug,
+               $( $Pn: VariantMetadata + FromVariant + sys::GodotFuncMarshal + sys::GodotFfi + Debug, )*
         {
             type Params = ($($Pn,)*);
             type Ret = $R;
 
+            const PARAM_COUNT: usize = [$($n,)*].len();
+
             #[inline]
             fn variant_type(index: i32) -> sys::VariantType {
                 match index {
@@ -105,14 +112,17 @@ macro_rules! impl_signature_for_tuple {
             }
 
             #[inline]
-            unsafe fn varcall<C : GodotClass>(
+            unsafe fn varcall<C: GodotClass, F>(
 				instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
-                func: fn(&mut C, Self::Params) -> Self::Ret,
+                func: F,
                 method_name: &str,
-            ) {
+            )
+            where
+                F: Fn(&mut C, Self::Params) -> Self::Ret,
+            {
     	        $crate::out!("varcall: {}", method_name);
 
                 let storage = unsafe { crate::private::as_storage::<C>(instance_ptr) };
@@ -122,8 +132,109 @@ macro_rules! impl_signature_for_tuple {
                     {
                         let variant = unsafe { &*(*args_ptr.offset($n) as *mut Variant) }; // TODO from_var_sys
                         let arg = <$Pn as FromVariant>::try_from_variant(variant)
-                            .unwrap_or_else(|e| param_error::<$Pn>(method_name, $n, variant));
+                            .unwrap_or_else(|_e| param_error::<$Pn>(method_name, $n, variant));
 
                         arg
                     },
-      
\ No newline at end of file
+                )* );
+
+                let rust_result = func(&mut *instance, args);
+                let variant_result = rust_result.to_variant();
+
+                // SAFETY: `ret` points to a valid, properly aligned `Variant` slot provided by Godot.
+                unsafe {
+                    *(ret as *mut Variant) = variant_result;
+                    (*err).error = sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK;
+                }
+            }
+
+            #[inline]
+            unsafe fn ptrcall<C: GodotClass, F>(
+                instance_ptr: sys::GDExtensionClassInstancePtr,
+                args_ptr: *const sys::GDExtensionConstTypePtr,
+                ret: sys::GDExtensionTypePtr,
+                func: F,
+                method_name: &str,
+            )
+            where
+                F: Fn(&mut C, Self::Params) -> Self::Ret,
+            {
+                $crate::out!("ptrcall: {}", method_name);
+
+                let storage = unsafe { crate::private::as_storage::<C>(instance_ptr) };
+                let mut instance = storage.get_mut();
+
+                // No `Variant` is constructed at all on this path -- each argument is read directly
+                // out of the type ptr Godot handed us, since typed GDScript already guarantees the
+                // encoding matches `$Pn`.
+                let args = ( $(
+                    {
+                        let type_ptr = unsafe { *args_ptr.offset($n) };
+                        unsafe { <$Pn as sys::GodotFfi>::from_sys(type_ptr) }
+                    },
+                )* );
+
+                let rust_result = func(&mut *instance, args);
+
+                // SAFETY: `ret` points to a valid, properly aligned `Self::Ret` slot provided by
+                // Godot, matching the type ptr encoding `Self::Ret` expects.
+                unsafe {
+                    std::ptr::write(ret as *mut Self::Ret, rust_result);
+                }
+            }
+        }
+    }
+}
+
+/// The erased ABI representation of a ptrcall parameter or return type.
+///
+/// Two methods whose parameter/return lists map to the same sequence of [`ErasedType`]s can share a
+/// single monomorphized ptrcall trampoline: the bytes occupied in the
+/// `*const *const c_void` ptrcall buffer are identical regardless of which concrete Rust type
+/// produced them, so the trampoline only needs to be generated once per distinct erased signature
+/// rather than once per method. This is the encoding GDNative's bindings generator calls "icall
+/// sharing".
+///
+/// See [`ErasedParam`] for the mapping from a concrete Rust type to its [`ErasedType`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErasedType {
+    /// Any [`EngineEnum`](crate::obj::EngineEnum)-implementing enum or bitfield; all of these are
+    /// passed as a 64-bit integer ordinal, e.g. `Vector3Axis`-like scalar enums.
+    Int64,
+    /// Any `Gd<T>` pointing at an engine or user object; all of these are passed as an opaque,
+    /// type-erased object pointer.
+    ObjectPtr,
+    /// A builtin type with its own in-memory representation (`Vector3`, `Color`, `Transform3D`, ...).
+    /// Builtins are not shareable with each other, since their layouts differ.
+    Builtin(VariantType),
+}
+
+/// Types whose ptrcall ABI can be collapsed to a shared [`ErasedType`], for trampoline sharing.
+///
+/// This is a classification layer on top of [`SignatureTuple`]: where `SignatureTuple::variant_type()`
+/// reports the precise Godot `VariantType` of a parameter, `ErasedParam::erased_type()` reports only
+/// as much as a ptrcall trampoline actually needs to distinguish -- collapsing e.g. `PropertyHint` and
+/// `PropertyUsageFlags` to the same [`ErasedType::Int64`] bucket.
+///
+/// Deliberately *not* blanket-implemented for `T: EngineEnum` or `T: VariantMetadata`: the method
+/// registration macro (`gdext_register_method!`) that would consume this to pick a shared ptrcall
+/// trampoline per distinct erased signature isn't implemented in this tree yet, so there is no call
+/// site to drive a coherent set of blanket impls from. Implement this manually per type as that
+/// registration path is built out.
+pub trait ErasedParam {
+    fn erased_type() -> ErasedType;
+}
+
+/// Produces a panic for a method argument that failed to convert from its passed [`Variant`].
+fn param_error<P>(method_name: &str, index: i32, arg: &Variant) -> ! {
+    let param_ty = std::any::type_name::<P>();
+    panic!(
+        "{method_name}: parameter [{index}] of type {param_ty} could not be converted from {arg:?}"
+    )
+}
+
+impl_signature_for_tuple!(R);
+impl_signature_for_tuple!(R, P0: 0);
+impl_signature_for_tuple!(R, P0: 0, P1: 1);
+impl_signature_for_tuple!(R, P0: 0, P1: 1, P2: 2);
+impl_signature_for_tuple!(R, P0: 0, P1: 1, P2: 2, P3: 3);
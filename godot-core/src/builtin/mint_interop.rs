@@ -0,0 +1,109 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Optional bridge to the `mint` crate (see the module doc at the top of `builtin/mod.rs` for why
+// `mint` isn't used as this crate's primary vector/matrix representation: it would prevent
+// operator overloading on our own types). This module only adds `From`/`Into` conversions, so the
+// native, operator-friendly API stays untouched; `mint` is purely an opt-in interchange format for
+// passing data to other math/graphics crates without exposing `glam`'s own (volatile) types.
+//
+// `mint`'s types are plain, generic structs (`mint::Vector3<T>`, ...), so there is no FFI/layout
+// concern here, unlike the `glam` conversions in `glam_helpers.rs`.
+
+use super::{real, Basis, Quaternion, Vector3, Vector4};
+
+impl From<Vector3> for mint::Vector3<real> {
+    fn from(v: Vector3) -> Self {
+        mint::Vector3 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+impl From<mint::Vector3<real>> for Vector3 {
+    fn from(v: mint::Vector3<real>) -> Self {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector4> for mint::Vector4<real> {
+    fn from(v: Vector4) -> Self {
+        mint::Vector4 {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+            w: v.w,
+        }
+    }
+}
+
+impl From<mint::Vector4<real>> for Vector4 {
+    fn from(v: mint::Vector4<real>) -> Self {
+        Vector4::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Quaternion> for mint::Quaternion<real> {
+    fn from(q: Quaternion) -> Self {
+        mint::Quaternion {
+            v: mint::Vector3 {
+                x: q.x,
+                y: q.y,
+                z: q.z,
+            },
+            s: q.w,
+        }
+    }
+}
+
+impl From<mint::Quaternion<real>> for Quaternion {
+    fn from(q: mint::Quaternion<real>) -> Self {
+        Quaternion::new(q.v.x, q.v.y, q.v.z, q.s)
+    }
+}
+
+/// Converts to `mint`'s column-major 3x3 matrix representation.
+///
+/// `Basis::rows` stores the matrix by row, so each `mint` column is assembled from the matching
+/// component of all three rows.
+impl From<Basis> for mint::ColumnMatrix3<real> {
+    fn from(basis: Basis) -> Self {
+        let [r0, r1, r2] = basis.rows;
+
+        mint::ColumnMatrix3 {
+            x: mint::Vector3 {
+                x: r0.x,
+                y: r1.x,
+                z: r2.x,
+            },
+            y: mint::Vector3 {
+                x: r0.y,
+                y: r1.y,
+                z: r2.y,
+            },
+            z: mint::Vector3 {
+                x: r0.z,
+                y: r1.z,
+                z: r2.z,
+            },
+        }
+    }
+}
+
+impl From<mint::ColumnMatrix3<real>> for Basis {
+    fn from(mat: mint::ColumnMatrix3<real>) -> Self {
+        Basis {
+            rows: [
+                Vector3::new(mat.x.x, mat.y.x, mat.z.x),
+                Vector3::new(mat.x.y, mat.y.y, mat.z.y),
+                Vector3::new(mat.x.z, mat.y.z, mat.z.z),
+            ],
+        }
+    }
+}
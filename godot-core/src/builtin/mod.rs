@@ -36,19 +36,25 @@
 pub use crate::{array, dict, varray};
 
 pub use array_inner::{Array, VariantArray};
+pub use array_ownership::*;
 pub use basis::*;
 pub use color::*;
+pub use curve::*;
 pub use dictionary_inner::Dictionary;
 pub use math::*;
 pub use node_path::*;
 pub use others::*;
 pub use packed_array::*;
+pub use plane::*;
 pub use projection::*;
 pub use quaternion::*;
+pub use rect2::*;
 pub use string::*;
 pub use string_name::*;
 pub use transform2d::*;
+pub use transform2d_typed::*;
 pub use transform3d::*;
+pub use transform_ops::*;
 pub use variant::*;
 pub use vector2::*;
 pub use vector2i::*;
@@ -83,20 +89,31 @@ mod array_inner;
 #[path = "dictionary.rs"]
 mod dictionary_inner;
 
+mod array_ctor;
+mod array_macros;
+mod array_ownership;
+mod array_typed;
 mod basis;
 mod color;
+mod curve;
 mod glam_helpers;
 mod math;
+#[cfg(feature = "mint")]
+mod mint_interop;
 mod node_path;
 mod others;
 mod packed_array;
+mod plane;
 mod projection;
 mod quaternion;
+mod rect2;
 mod string;
 mod string_chars;
 mod string_name;
 mod transform2d;
+mod transform2d_typed;
 mod transform3d;
+mod transform_ops;
 mod variant;
 mod vector2;
 mod vector2i;
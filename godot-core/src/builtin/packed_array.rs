@@ -0,0 +1,256 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use std::fmt;
+use std::io;
+use sys::{ffi_methods, interface_fn, GodotFfi};
+
+use crate::builtin::{inner, to_i64, to_usize, Color, Vector3};
+
+/// Declares a `Packed*Array` type backed by one of Godot's contiguous, copy-on-write element
+/// buffers.
+///
+/// Unlike [`Array<T>`](super::Array), a packed array stores its elements inline (not as
+/// [`Variant`](super::Variant)s), so the engine exposes a raw pointer to the first element plus a
+/// length -- which is exactly what [`as_slice`]/[`as_mut_slice`] borrow from, with no copy.
+///
+/// [`as_slice`]: Self::as_slice
+/// [`as_mut_slice`]: Self::as_mut_slice
+macro_rules! impl_packed_array {
+    (
+        $PackedArray:ident,
+        $Element:ty,
+        $OpaqueType:ident,
+        $InnerType:ident,
+        $gd_prefix:ident
+    ) => {
+        #[doc = concat!("Godot's `", stringify!($PackedArray), "` type.")]
+        ///
+        /// Like the other packed array types, this acts as a reference-counted, copy-on-write
+        /// container: cloning is cheap until one of the clones is mutated.
+        #[repr(C)]
+        pub struct $PackedArray {
+            opaque: sys::types::$OpaqueType,
+        }
+
+        impl $PackedArray {
+            fn from_opaque(opaque: sys::types::$OpaqueType) -> Self {
+                Self { opaque }
+            }
+
+            /// Constructs an empty array.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Returns the number of elements in the array.
+            pub fn len(&self) -> usize {
+                to_usize(self.as_inner().size())
+            }
+
+            /// Returns `true` if the array is empty.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Clears the array, removing all elements.
+            pub fn clear(&mut self) {
+                self.as_inner().clear();
+            }
+
+            /// Resizes the array to contain a different number of elements. If the new size is
+            /// smaller, elements are removed from the end. If the new size is larger, new
+            /// elements are zero-initialized.
+            pub fn resize(&mut self, size: usize) {
+                self.as_inner().resize(to_i64(size));
+            }
+
+            /// Appends an element to the end of the array.
+            pub fn push(&mut self, value: $Element) {
+                self.as_inner().push_back(value);
+            }
+
+            /// Returns a copy of the element at `index`.
+            ///
+            /// # Panics
+            /// If `index` is out of bounds.
+            pub fn get(&self, index: usize) -> $Element {
+                let slice = self.as_slice();
+                assert!(
+                    index < slice.len(),
+                    "Array index {index} out of bounds: length is {}",
+                    slice.len()
+                );
+                slice[index]
+            }
+
+            /// Writes `value` into the element at `index`.
+            ///
+            /// # Panics
+            /// If `index` is out of bounds.
+            pub fn set(&mut self, index: usize, value: $Element) {
+                self.as_mut_slice()[index] = value;
+            }
+
+            /// Returns a `Vec` containing a copy of all elements in the array.
+            pub fn to_vec(&self) -> Vec<$Element> {
+                self.as_slice().to_vec()
+            }
+
+            /// Returns a shared slice over the array's backing buffer, with no copy.
+            ///
+            /// # Aliasing invariant
+            /// This array uses copy-on-write storage: the pointer backing this slice is only
+            /// stable as long as no *other* handle sharing the same storage is mutated and as
+            /// long as `self` itself isn't resized or dropped while the slice is alive. Both are
+            /// already enforced by the borrow checker, since this method borrows `self`
+            /// immutably and a reallocation can only be triggered through a `&mut self` method.
+            pub fn as_slice(&self) -> &[$Element] {
+                let len = self.len();
+                if len == 0 {
+                    return &[];
+                }
+
+                // SAFETY: `self` holds at least one initialized element, so indexing 0 is valid;
+                // the engine guarantees the `len` elements starting there are contiguous.
+                unsafe {
+                    let first = sys::paste::paste! {
+                        interface_fn!([<$gd_prefix _operator_index_const>])(self.sys(), 0)
+                    };
+                    std::slice::from_raw_parts(first as *const $Element, len)
+                }
+            }
+
+            /// Returns a mutable slice over the array's backing buffer, with no copy.
+            ///
+            /// See [`as_slice`](Self::as_slice) for the aliasing invariant this relies on.
+            pub fn as_mut_slice(&mut self) -> &mut [$Element] {
+                let len = self.len();
+                if len == 0 {
+                    return &mut [];
+                }
+
+                // SAFETY: see `as_slice`; `&mut self` additionally guarantees no other borrow of
+                // this array's storage is alive.
+                unsafe {
+                    let first = sys::paste::paste! {
+                        interface_fn!([<$gd_prefix _operator_index>])(self.sys_mut(), 0)
+                    };
+                    std::slice::from_raw_parts_mut(first as *mut $Element, len)
+                }
+            }
+
+            #[doc(hidden)]
+            pub fn as_inner(&self) -> inner::$InnerType {
+                inner::$InnerType::from_outer(self)
+            }
+        }
+
+        sys::paste::paste! {
+            impl_builtin_traits_inner!(Default for $PackedArray => [<$gd_prefix _construct_default>]);
+            impl_builtin_traits_inner!(Clone for $PackedArray => [<$gd_prefix _construct_copy>]);
+            impl_builtin_traits_inner!(Drop for $PackedArray => [<$gd_prefix _destroy>]);
+            impl_builtin_traits_inner!(PartialEq for $PackedArray => [<$gd_prefix _operator_equal>]);
+        }
+
+        impl GodotFfi for $PackedArray {
+            ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; ..; Default }
+        }
+
+        impl fmt::Debug for $PackedArray {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                self.as_slice().fmt(f)
+            }
+        }
+
+        impl FromIterator<$Element> for $PackedArray {
+            fn from_iter<I: IntoIterator<Item = $Element>>(iter: I) -> Self {
+                let mut array = Self::new();
+                for value in iter {
+                    array.push(value);
+                }
+                array
+            }
+        }
+
+        impl From<&[$Element]> for $PackedArray {
+            fn from(slice: &[$Element]) -> Self {
+                slice.iter().copied().collect()
+            }
+        }
+    };
+}
+
+impl_packed_array!(
+    PackedByteArray,
+    u8,
+    OpaquePackedByteArray,
+    InnerPackedByteArray,
+    packed_byte_array
+);
+impl_packed_array!(
+    PackedInt32Array,
+    i32,
+    OpaquePackedInt32Array,
+    InnerPackedInt32Array,
+    packed_int32_array
+);
+impl_packed_array!(
+    PackedInt64Array,
+    i64,
+    OpaquePackedInt64Array,
+    InnerPackedInt64Array,
+    packed_int64_array
+);
+impl_packed_array!(
+    PackedFloat32Array,
+    f32,
+    OpaquePackedFloat32Array,
+    InnerPackedFloat32Array,
+    packed_float32_array
+);
+impl_packed_array!(
+    PackedFloat64Array,
+    f64,
+    OpaquePackedFloat64Array,
+    InnerPackedFloat64Array,
+    packed_float64_array
+);
+impl_packed_array!(
+    PackedVector3Array,
+    Vector3,
+    OpaquePackedVector3Array,
+    InnerPackedVector3Array,
+    packed_vector3_array
+);
+impl_packed_array!(
+    PackedColorArray,
+    Color,
+    OpaquePackedColorArray,
+    InnerPackedColorArray,
+    packed_color_array
+);
+
+/// Appends written bytes to the end of the array, growing it as needed -- mirroring
+/// `Vec<u8>`'s own (non-existent) `Write` impl semantics, since a `Vec` doesn't need one: slices
+/// already cover that case, and this is the packed-array equivalent.
+///
+/// There's deliberately no matching `Read` impl: reading needs a cursor position, which this type
+/// doesn't track. Wrap [`PackedByteArray::as_slice`] in a [`std::io::Cursor`] for streaming reads
+/// instead -- `&[u8]` already implements `Read`.
+impl io::Write for PackedByteArray {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let start = self.len();
+        self.resize(start + buf.len());
+        self.as_mut_slice()[start..].copy_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use super::{real, Vector3};
+
+/// A plane in Hessian normal form: `normal . point + d = 0`, where `normal` is a unit vector and
+/// `d` is the signed distance from the plane to the origin, i.e. the plane lies at `-d` along
+/// `normal`.
+///
+/// _Godot equivalent: Plane_
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct Plane {
+    /// The normal of the plane, which must be of unit length.
+    pub normal: Vector3,
+
+    /// The signed distance from the plane to the origin (the value of [`Plane::distance_to`] at
+    /// the origin); the plane lies at `-d` along `normal`.
+    pub d: real,
+}
+
+impl Plane {
+    /// Creates a plane from the given `normal` and offset `d`, as in `normal . point + d = 0`.
+    ///
+    /// `normal` is not required to already be normalized; use [`Self::normalized`] if needed.
+    pub const fn new(normal: Vector3, d: real) -> Self {
+        Self { normal, d }
+    }
+
+    /// Returns `self` with its `normal` normalized, and `d` scaled to match.
+    pub fn normalized(self) -> Self {
+        let length = self.normal.length();
+
+        Self {
+            normal: self.normal / length,
+            d: self.d / length,
+        }
+    }
+
+    /// The shortest distance from `point` to the plane, with the plane's front side (the side its
+    /// normal points to) being positive.
+    pub fn distance_to(self, point: Vector3) -> real {
+        self.normal.dot(point) + self.d
+    }
+}
+
+/// Formats the plane like Godot: `(normal, d)`.
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.normal, self.d)
+    }
+}
+
+impl GodotFfi for Plane {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
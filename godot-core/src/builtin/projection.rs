@@ -1,4 +1,3 @@
-
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
@@ -10,7 +9,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use super::glam_helpers::{GlamConv, GlamType};
-use super::{inner::InnerProjection, Plane, Transform3D, Vector2, Vector4};
+use super::{inner::InnerProjection, Plane, Transform3D, Vector2, Vector3, Vector4};
 use super::{real, RMat4, RealConv};
 
 /// A 4x4 matrix used for 3D projective transformations. It can represent
@@ -26,6 +25,7 @@ use super::{real, RMat4, RealConv};
 /// Note: The current implementation largely makes calls to godot for its
 /// methods and as such are not as performant as other types.
 #[derive(Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Projection {
     /// The columns of the projection matrix.
@@ -67,6 +67,16 @@ impl Projection {
         Self { cols: [x, y, z, w] }
     }
 
+    /// Godot's `get_fovy`: the Y field of view (in degrees) that keeps the same *X* field of view
+    /// when the aspect ratio changes from 1.0 to `aspect`. Used to implement `flip_fov`, which
+    /// swaps which axis receives the full field of view instead of the aspect-scaled one.
+    fn flip_fovy(fov_y_degrees: real, aspect: real) -> real {
+        ((fov_y_degrees.to_radians() / 2.0).tan() * aspect)
+            .atan()
+            .to_degrees()
+            * 2.0
+    }
+
     /// Creates a new Projection that projects positions from a depth range of
     /// -1 to 1 to one that ranges from 0 to 1, and flips the projected
     /// positions vertically, according to flip_y.
@@ -107,6 +117,9 @@ impl Projection {
     /// Creates a new Projection that projects positions in a frustum with the
     /// given clipping planes.
     ///
+    /// Computed natively (the classic OpenGL `glFrustum` matrix) rather than delegating to Godot,
+    /// since `glam` has no general off-center frustum constructor to build on directly.
+    ///
     /// _Godot equivalent: Projection.create_frustum()_
     pub fn create_frustum(
         left: real,
@@ -116,13 +129,18 @@ impl Projection {
         near: real,
         far: real,
     ) -> Self {
-        InnerProjection::create_frustum(
-            left.as_f64(),
-            right.as_f64(),
-            bottom.as_f64(),
-            top.as_f64(),
-            near.as_f64(),
-            far.as_f64(),
+        let x = 2.0 * near / (right - left);
+        let y = 2.0 * near / (top - bottom);
+        let a = (right + left) / (right - left);
+        let b = (top + bottom) / (top - bottom);
+        let c = -(far + near) / (far - near);
+        let d = -2.0 * far * near / (far - near);
+
+        Self::from_cols(
+            Vector4::new(x, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, y, 0.0, 0.0),
+            Vector4::new(a, b, c, -1.0),
+            Vector4::new(0.0, 0.0, d, 0.0),
         )
     }
 
@@ -141,13 +159,15 @@ impl Projection {
         far: real,
         flip_fov: bool,
     ) -> Self {
-        InnerProjection::create_frustum_aspect(
-            size.as_f64(),
-            aspect.as_f64(),
-            offset,
-            near.as_f64(),
-            far.as_f64(),
-            flip_fov,
+        let size = if flip_fov { size } else { size * aspect };
+
+        Self::create_frustum(
+            -size / 2.0 + offset.x,
+            size / 2.0 + offset.x,
+            -size / aspect / 2.0 + offset.y,
+            size / aspect / 2.0 + offset.y,
+            near,
+            far,
         )
     }
 
@@ -163,14 +183,9 @@ impl Projection {
         near: real,
         far: real,
     ) -> Self {
-        InnerProjection::create_orthogonal(
-            left.as_f64(),
-            right.as_f64(),
-            bottom.as_f64(),
-            top.as_f64(),
-            near.as_f64(),
-            far.as_f64(),
-        )
+        Self::from_glam(RMat4::orthographic_rh_gl(
+            left, right, bottom, top, near, far,
+        ))
     }
 
     /// Creates a new Projection that projects positions using an orthogonal
@@ -187,12 +202,15 @@ impl Projection {
         far: real,
         flip_fov: bool,
     ) -> Self {
-        InnerProjection::create_orthogonal_aspect(
-            size.as_f64(),
-            aspect.as_f64(),
-            near.as_f64(),
-            far.as_f64(),
-            flip_fov,
+        let size = if flip_fov { size } else { size * aspect };
+
+        Self::create_orthogonal(
+            -size / 2.0,
+            size / 2.0,
+            -size / aspect / 2.0,
+            size / aspect / 2.0,
+            near,
+            far,
         )
     }
 
@@ -211,13 +229,18 @@ impl Projection {
         far: real,
         flip_fov: bool,
     ) -> Self {
-        InnerProjection::create_perspective(
-            fov_y.as_f64(),
-            aspect.as_f64(),
-            near.as_f64(),
-            far.as_f64(),
-            flip_fov,
-        )
+        let fov_y = if flip_fov {
+            Self::flip_fovy(fov_y, 1.0 / aspect)
+        } else {
+            fov_y
+        };
+
+        Self::from_glam(RMat4::perspective_rh_gl(
+            fov_y.to_radians(),
+            aspect,
+            near,
+            far,
+        ))
     }
 
     /// Creates a new Projection that projects positions using a perspective
@@ -253,6 +276,250 @@ impl Projection {
         )
     }
 
+    /// Creates a new Projection that projects positions using a perspective projection with the
+    /// given Y-axis field of view (in degrees), X:Y aspect ratio, and near clipping distance, with
+    /// the far clipping plane pushed out to infinity.
+    ///
+    /// `flip_fov` determines whether the projection's field of view is flipped over its diagonal.
+    ///
+    /// Depth range is `-1..1` (the same right-handed, OpenGL-style convention as
+    /// [`Self::create_perspective`]); built natively as the limit of that matrix as `far` tends to
+    /// infinity, since Godot exposes no equivalent factory.
+    pub fn create_perspective_infinite(
+        fov_y: real,
+        aspect: real,
+        near: real,
+        flip_fov: bool,
+    ) -> Self {
+        let fov_y = if flip_fov {
+            Self::flip_fovy(fov_y, 1.0 / aspect)
+        } else {
+            fov_y
+        };
+
+        let y = 1.0 / (fov_y.to_radians() / 2.0).tan();
+        let x = y / aspect;
+
+        Self::from_cols(
+            Vector4::new(x, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, y, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, -1.0, -1.0),
+            Vector4::new(0.0, 0.0, -2.0 * near, 0.0),
+        )
+    }
+
+    /// Creates a new Projection that projects positions using a perspective projection with the
+    /// given Y-axis field of view (in degrees), X:Y aspect ratio, and clipping planes, but with
+    /// `near` and `far` swapped in the depth mapping: `near` maps to depth `1` and `far` maps to
+    /// depth `-1` (instead of the usual `-1`/`1`).
+    ///
+    /// This "reverse-Z" layout spreads floating-point depth precision more evenly across the
+    /// frustum, which is the main reason modern renderers favor it over plain perspective
+    /// projection. Built natively on top of [`Self::create_perspective`], since Godot exposes no
+    /// equivalent factory.
+    ///
+    /// `flip_fov` determines whether the projection's field of view is flipped over its diagonal.
+    pub fn create_perspective_reverse_z(
+        fov_y: real,
+        aspect: real,
+        near: real,
+        far: real,
+        flip_fov: bool,
+    ) -> Self {
+        let mut proj = Self::create_perspective(fov_y, aspect, near, far, flip_fov);
+
+        // Negating the z-row (but not the w-row) flips the sign of the output depth while leaving
+        // the perspective divisor untouched, which swaps which endpoint maps to -1 vs. 1.
+        proj.cols[2].z = -proj.cols[2].z;
+        proj.cols[3].z = -proj.cols[3].z;
+
+        proj
+    }
+
     /// Return the determinant of the matrix.
     ///
-    /// _Godot equivalent: Projection.determinant()_
\ No newline at end of file
+    /// _Godot equivalent: Projection.determinant()_
+    pub fn determinant(&self) -> real {
+        self.to_glam().determinant()
+    }
+
+    /// Return the inverse of the matrix.
+    ///
+    /// _Godot equivalent: Projection.inverse()_
+    pub fn inverse(&self) -> Self {
+        Self::from_glam(self.to_glam().inverse())
+    }
+
+    /// Projects `p` (a point in view space) into normalized device coordinates, by multiplying
+    /// the homogeneous point `(p.x, p.y, p.z, 1)` by this matrix and dividing by the resulting
+    /// `w`.
+    ///
+    /// If `w` is zero, the point lies on the projection's eye plane and has no well-defined
+    /// projection; the un-divided `xyz` is returned as a documented sentinel in that case.
+    pub fn project_point(&self, p: Vector3) -> Vector3 {
+        let clip = *self * Vector4::new(p.x, p.y, p.z, 1.0);
+
+        if clip.w == 0.0 {
+            return Vector3::new(clip.x, clip.y, clip.z);
+        }
+
+        Vector3::new(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w)
+    }
+
+    /// Unprojects `ndc` (a point in normalized device coordinates) back into view space, by
+    /// multiplying the homogeneous point `(ndc.x, ndc.y, ndc.z, 1)` by this matrix's inverse and
+    /// dividing by the resulting `w`.
+    ///
+    /// Uses the native [`Self::inverse`], so this never needs to call into Godot. If `w` is zero,
+    /// the un-divided `xyz` is returned as a documented sentinel.
+    pub fn unproject_point(&self, ndc: Vector3) -> Vector3 {
+        self.inverse().project_point(ndc)
+    }
+
+    /// Returns the six clipping planes that form the frustum of this projection, in the order
+    /// left, right, bottom, top, near, far. Each plane's normal points inward, toward the
+    /// frustum's interior, so [`Plane::distance_to`] is positive for points inside the frustum.
+    ///
+    /// Uses the Gribb-Hartmann row-combination method: each plane is a signed sum of two rows of
+    /// the projection matrix, then normalized so that its normal is unit length.
+    ///
+    /// This is invaluable for frustum culling and has no Godot equivalent exposed here.
+    pub fn get_frustum_planes(&self) -> [Plane; 6] {
+        // `self.cols` are columns, so row `i` is made up of the i-th component of every column.
+        let r0 = Vector4::new(
+            self.cols[0].x,
+            self.cols[1].x,
+            self.cols[2].x,
+            self.cols[3].x,
+        );
+        let r1 = Vector4::new(
+            self.cols[0].y,
+            self.cols[1].y,
+            self.cols[2].y,
+            self.cols[3].y,
+        );
+        let r2 = Vector4::new(
+            self.cols[0].z,
+            self.cols[1].z,
+            self.cols[2].z,
+            self.cols[3].z,
+        );
+        let r3 = Vector4::new(
+            self.cols[0].w,
+            self.cols[1].w,
+            self.cols[2].w,
+            self.cols[3].w,
+        );
+
+        let combine = |v: Vector4| {
+            let normal = Vector3::new(v.x, v.y, v.z);
+            let length = normal.length();
+
+            Plane::new(normal / length, v.w / length)
+        };
+
+        [
+            combine(r3 + r0),
+            combine(r3 - r0),
+            combine(r3 + r1),
+            combine(r3 - r1),
+            combine(r3 + r2),
+            combine(r3 - r2),
+        ]
+    }
+
+    /// Returns the dimensions of the far plane of the projection, measured at its intersection
+    /// with the Z axis.
+    ///
+    /// _Godot equivalent: Projection.get_far_plane_half_extents()_
+    pub fn get_far_plane_half_extents(&self) -> Vector2 {
+        let planes = self.get_frustum_planes();
+        let (left, right, bottom, top, _near, far) = (
+            planes[0], planes[1], planes[2], planes[3], planes[4], planes[5],
+        );
+
+        // Intersect the far plane with the left/right and bottom/top planes to find the corner of
+        // the frustum, then take its distance from the Z axis along X and Y respectively.
+        let right_point = intersect_3(&right, &top, &far).unwrap_or(Vector3::ZERO);
+        let left_point = intersect_3(&left, &top, &far).unwrap_or(Vector3::ZERO);
+        let bottom_point = intersect_3(&right, &bottom, &far).unwrap_or(Vector3::ZERO);
+
+        Vector2::new(
+            (right_point.x - left_point.x).abs() / 2.0,
+            (right_point.y - bottom_point.y).abs() / 2.0,
+        )
+    }
+}
+
+/// Returns the point where three planes all intersect, or `None` if any two of them are parallel.
+///
+/// Used by [`Projection::get_far_plane_half_extents`] to turn frustum planes back into corner
+/// points.
+fn intersect_3(a: &Plane, b: &Plane, c: &Plane) -> Option<Vector3> {
+    let denom = a.normal.cross(b.normal).dot(c.normal);
+    if denom.abs() < super::CMP_EPSILON {
+        return None;
+    }
+
+    let result = (b.normal.cross(c.normal) * -a.d)
+        + (c.normal.cross(a.normal) * -b.d)
+        + (a.normal.cross(b.normal) * -c.d);
+
+    Some(result / denom)
+}
+
+impl GlamType for RMat4 {
+    type Mapped = Projection;
+
+    fn to_front(&self) -> Self::Mapped {
+        Projection::from_cols(
+            Vector4::from_glam(self.x_axis),
+            Vector4::from_glam(self.y_axis),
+            Vector4::from_glam(self.z_axis),
+            Vector4::from_glam(self.w_axis),
+        )
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        RMat4::from_cols(
+            mapped.cols[0].to_glam(),
+            mapped.cols[1].to_glam(),
+            mapped.cols[2].to_glam(),
+            mapped.cols[3].to_glam(),
+        )
+    }
+}
+
+impl GlamConv for Projection {
+    type Glam = RMat4;
+}
+
+/// Composes two projections, as if the right side's transformation is applied before the left's.
+impl Mul for Projection {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_glam(self.to_glam() * rhs.to_glam())
+    }
+}
+
+/// Transforms a homogeneous (clip-space) column vector by this projection.
+impl Mul<Vector4> for Projection {
+    type Output = Vector4;
+
+    fn mul(self, rhs: Vector4) -> Self::Output {
+        Vector4::from_glam(self.to_glam() * rhs.to_glam())
+    }
+}
+
+impl From<RMat4> for Projection {
+    fn from(mat: RMat4) -> Self {
+        Self::from_glam(mat)
+    }
+}
+
+impl From<Projection> for RMat4 {
+    fn from(projection: Projection) -> Self {
+        projection.to_glam()
+    }
+}
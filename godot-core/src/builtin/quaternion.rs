@@ -1,4 +1,3 @@
-
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
@@ -12,10 +11,12 @@ use sys::{ffi_methods, GodotFfi};
 use crate::builtin::glam_helpers::{GlamConv, GlamType};
 use crate::builtin::{inner, math::*, vector3::*};
 
+use super::real_consts::PI;
 use super::{real, RQuat};
 use super::{Basis, EulerOrder};
 
-#[derive(Copy, Clone, PartialEq, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Quaternion {
     pub x: real,
@@ -29,6 +30,32 @@ impl Quaternion {
         Self { x, y, z, w }
     }
 
+    /// Returns the shortest-arc rotation that takes the unit vector `from` onto the unit vector
+    /// `to`, e.g. for aiming or aligning geometry.
+    pub fn from_rotation_arc(from: Vector3, to: Vector3) -> Self {
+        let from = from.normalized();
+        let to = to.normalized();
+        let d = from.dot(to);
+
+        if d >= 1.0 - CMP_EPSILON {
+            // `from` and `to` already point the same way.
+            Self::new(0.0, 0.0, 0.0, 1.0)
+        } else if d <= -1.0 + CMP_EPSILON {
+            // `from` and `to` are antiparallel: any axis orthogonal to `from` works, so pick the
+            // one obtained by crossing with a world axis, falling back to another one if `from`
+            // happens to be parallel to the first.
+            let mut axis = from.cross(Vector3::new(1.0, 0.0, 0.0));
+            if axis.length_squared() < CMP_EPSILON {
+                axis = from.cross(Vector3::new(0.0, 1.0, 0.0));
+            }
+
+            Self::from_angle_axis(axis.normalized(), PI)
+        } else {
+            let axis = from.cross(to);
+            Self::new(axis.x, axis.y, axis.z, 1.0 + d).normalized()
+        }
+    }
+
     pub fn from_angle_axis(axis: Vector3, angle: real) -> Self {
         let d = axis.length();
         if d == 0.0 {
@@ -53,6 +80,11 @@ impl Quaternion {
         self.glam2(&with, RQuat::dot)
     }
 
+    /// Returns the exponential of this quaternion, treating `self` as a pure (log-space)
+    /// quaternion, i.e. a rotation axis scaled by its angle, packed into the `x`/`y`/`z`
+    /// components with `w` unused.
+    ///
+    /// Inverse of [`log()`](Self::log).
     pub fn to_exp(self) -> Self {
         let mut v = Vector3::new(self.x, self.y, self.z);
         let theta = v.length();
@@ -131,6 +163,10 @@ impl Quaternion {
         self.dot(self)
     }
 
+    /// Returns the logarithm of this quaternion, i.e. its rotation axis scaled by its angle,
+    /// packed into the `x`/`y`/`z` components with `w` set to `0.0`.
+    ///
+    /// Inverse of [`to_exp()`](Self::to_exp).
     pub fn log(self) -> Self {
         let v = self.get_axis() * self.get_angle();
         Quaternion::new(v.x, v.y, v.z, 0.0)
@@ -180,21 +216,150 @@ impl Quaternion {
         inv_factor * self + new_factor * to
     }
 
-    // pub fn spherical_cubic_interpolate(self, b: Self, pre_a: Self, post_b: Self, weight: real) -> Self {}
-    // TODO: Implement godot's function in rust
-    /*
-        pub fn spherical_cubic_interpolate_in_time(
-            self,
-            b: Self,
-            pre_a: Self,
-            post_b: Self,
-            weight: real,
-            b_t: real,
-            pre_a_t: real,
-            post_b_t: real,
-        ) -> Self {
+    /// Performs a spherical cubic interpolation between this quaternion and `b`, using `pre_a`
+    /// and `post_b` as tangent (off-path) control points, e.g. to smoothly interpolate between
+    /// rotation keyframes in an animation.
+    ///
+    /// _Godot equivalent: `Quaternion.spherical_cubic_interpolate()`_
+    pub fn spherical_cubic_interpolate(
+        self,
+        b: Self,
+        pre_a: Self,
+        post_b: Self,
+        weight: real,
+    ) -> Self {
+        let from = self.normalized();
+        let mut b = b.normalized();
+        let mut pre_a = pre_a.normalized();
+        let mut post_b = post_b.normalized();
+
+        // Align every quaternion to the hemisphere of `from`, so interpolation takes the
+        // shortest path instead of spinning the long way around.
+        if from.dot(pre_a) < 0.0 {
+            pre_a = -pre_a;
+        }
+        if from.dot(b) < 0.0 {
+            b = -b;
         }
-    */
+        if b.dot(post_b) < 0.0 {
+            post_b = -post_b;
+        }
+
+        // Compute the tangent at `from` in log space, then blend component-wise with a scalar
+        // Catmull-Rom spline (the same curve `Vector3::cubic_interpolate` uses), and map back
+        // through `exp()`.
+        let ln_pre = (from.inverse() * pre_a).log();
+        let ln_to = (from.inverse() * b).log();
+        let ln_post = (from.inverse() * post_b).log();
+        let ln = Vector3::new(
+            cubic_interpolate(0.0, ln_to.x, ln_pre.x, ln_post.x, weight),
+            cubic_interpolate(0.0, ln_to.y, ln_pre.y, ln_post.y, weight),
+            cubic_interpolate(0.0, ln_to.z, ln_pre.z, ln_post.z, weight),
+        );
+        let q1 = from * Quaternion::new(ln.x, ln.y, ln.z, 0.0).to_exp();
+
+        // Repeat the same computation anchored at `b`, walking backwards, to cancel out the
+        // ambiguity inherent in the expmap. `b` itself sits in the `to` slot as `0.0`, and the
+        // `from`-relative log takes the `from` slot, so this still returns `b` at `weight == 1.0`.
+        let ln_pre = (b.inverse() * pre_a).log();
+        let ln_to = (b.inverse() * from).log();
+        let ln_post = (b.inverse() * post_b).log();
+        let ln = Vector3::new(
+            cubic_interpolate(ln_to.x, 0.0, ln_pre.x, ln_post.x, weight),
+            cubic_interpolate(ln_to.y, 0.0, ln_pre.y, ln_post.y, weight),
+            cubic_interpolate(ln_to.z, 0.0, ln_pre.z, ln_post.z, weight),
+        );
+        let q2 = b * Quaternion::new(ln.x, ln.y, ln.z, 0.0).to_exp();
+
+        q1.slerp(q2, weight)
+    }
+
+    /// Like [`spherical_cubic_interpolate()`](Self::spherical_cubic_interpolate), but the
+    /// tangent points are spaced in time by `b_t`/`pre_a_t`/`post_b_t` rather than assumed to be
+    /// evenly spaced, matching how keyframes in an animation track are rarely equidistant.
+    ///
+    /// _Godot equivalent: `Quaternion.spherical_cubic_interpolate_in_time()`_
+    #[allow(clippy::too_many_arguments)]
+    pub fn spherical_cubic_interpolate_in_time(
+        self,
+        b: Self,
+        pre_a: Self,
+        post_b: Self,
+        weight: real,
+        b_t: real,
+        pre_a_t: real,
+        post_b_t: real,
+    ) -> Self {
+        let from = self.normalized();
+        let mut b = b.normalized();
+        let mut pre_a = pre_a.normalized();
+        let mut post_b = post_b.normalized();
+
+        if from.dot(pre_a) < 0.0 {
+            pre_a = -pre_a;
+        }
+        if from.dot(b) < 0.0 {
+            b = -b;
+        }
+        if b.dot(post_b) < 0.0 {
+            post_b = -post_b;
+        }
+
+        let ln_pre = (from.inverse() * pre_a).log();
+        let ln_to = (from.inverse() * b).log();
+        let ln_post = (from.inverse() * post_b).log();
+        let ln = Vector3::new(
+            cubic_interpolate_in_time(
+                0.0, ln_to.x, ln_pre.x, ln_post.x, weight, b_t, pre_a_t, post_b_t,
+            ),
+            cubic_interpolate_in_time(
+                0.0, ln_to.y, ln_pre.y, ln_post.y, weight, b_t, pre_a_t, post_b_t,
+            ),
+            cubic_interpolate_in_time(
+                0.0, ln_to.z, ln_pre.z, ln_post.z, weight, b_t, pre_a_t, post_b_t,
+            ),
+        );
+        let q1 = from * Quaternion::new(ln.x, ln.y, ln.z, 0.0).to_exp();
+
+        let ln_pre = (b.inverse() * pre_a).log();
+        let ln_to = (b.inverse() * from).log();
+        let ln_post = (b.inverse() * post_b).log();
+        let ln = Vector3::new(
+            cubic_interpolate_in_time(
+                ln_to.x,
+                0.0,
+                ln_pre.x,
+                ln_post.x,
+                weight,
+                -b_t,
+                pre_a_t - b_t,
+                post_b_t - b_t,
+            ),
+            cubic_interpolate_in_time(
+                ln_to.y,
+                0.0,
+                ln_pre.y,
+                ln_post.y,
+                weight,
+                -b_t,
+                pre_a_t - b_t,
+                post_b_t - b_t,
+            ),
+            cubic_interpolate_in_time(
+                ln_to.z,
+                0.0,
+                ln_pre.z,
+                ln_post.z,
+                weight,
+                -b_t,
+                pre_a_t - b_t,
+                post_b_t - b_t,
+            ),
+        );
+        let q2 = b * Quaternion::new(ln.x, ln.y, ln.z, 0.0).to_exp();
+
+        q1.slerp(q2, weight)
+    }
 
     #[doc(hidden)]
     pub fn as_inner(&self) -> inner::InnerQuaternion {
@@ -244,4 +409,86 @@ impl Mul<Quaternion> for Quaternion {
     type Output = Self;
 
     fn mul(self, other: Quaternion) -> Self {
-        // TODO use super::glam?
\ No newline at end of file
+        self.glam2(&other, |a, b| a * b)
+    }
+}
+
+impl Mul<real> for Quaternion {
+    type Output = Self;
+
+    fn mul(self, scale: real) -> Self {
+        Self::new(
+            self.x * scale,
+            self.y * scale,
+            self.z * scale,
+            self.w * scale,
+        )
+    }
+}
+
+impl Mul<Quaternion> for real {
+    type Output = Quaternion;
+
+    fn mul(self, quat: Quaternion) -> Quaternion {
+        quat * self
+    }
+}
+
+impl Div<real> for Quaternion {
+    type Output = Self;
+
+    fn div(self, scale: real) -> Self {
+        Self::new(
+            self.x / scale,
+            self.y / scale,
+            self.z / scale,
+            self.w / scale,
+        )
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z, -self.w)
+    }
+}
+
+impl std::fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {}, {})", self.x, self.y, self.z, self.w)
+    }
+}
+
+impl GodotFfi for Quaternion {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+impl GlamType for RQuat {
+    type Mapped = Quaternion;
+
+    fn to_front(&self) -> Quaternion {
+        Quaternion::new(self.x, self.y, self.z, self.w)
+    }
+
+    fn from_front(mapped: &Quaternion) -> Self {
+        RQuat::from_xyzw(mapped.x, mapped.y, mapped.z, mapped.w)
+    }
+}
+
+impl GlamConv for Quaternion {
+    type Glam = RQuat;
+}
+
+impl Quaternion {
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(quat: RQuat) -> Self {
+        <RQuat as GlamType>::to_front(&quat)
+    }
+
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RQuat {
+        GlamConv::to_glam(&self)
+    }
+}
@@ -0,0 +1,83 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use sys::{ffi_methods, GodotFfi};
+
+use crate::builtin::{real, Vector2};
+
+/// Axis-aligned bounding box in 2D space, represented by a starting position and a size.
+///
+/// _Godot equivalent: `Rect2`_
+#[derive(Default, Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Rect2 {
+    /// The position of the rectangle's top-left corner.
+    ///
+    /// _Godot equivalent: `Rect2.position`_
+    pub position: Vector2,
+
+    /// The size of the rectangle, extending from [`position`](Self::position).
+    ///
+    /// _Godot equivalent: `Rect2.size`_
+    pub size: Vector2,
+}
+
+impl Rect2 {
+    /// Create a new `Rect2` from a position and a size.
+    ///
+    /// _Godot equivalent: `Rect2(Vector2 position, Vector2 size)`_
+    pub const fn new(position: Vector2, size: Vector2) -> Self {
+        Self { position, size }
+    }
+
+    /// Create a new `Rect2` from its four components.
+    ///
+    /// _Godot equivalent: `Rect2(float x, float y, float width, float height)`_
+    pub const fn from_components(x: real, y: real, width: real, height: real) -> Self {
+        Self::new(Vector2::new(x, y), Vector2::new(width, height))
+    }
+
+    /// The ending corner, i.e. [`position`](Self::position) `+` [`size`](Self::size).
+    ///
+    /// _Godot equivalent: `Rect2.end`_
+    pub fn end(&self) -> Vector2 {
+        self.position + self.size
+    }
+
+    /// The four corners of this rect, in the order top-left, top-right, bottom-right,
+    /// bottom-left (assuming a non-negative [`size`](Self::size)).
+    pub(crate) fn corners(&self) -> [Vector2; 4] {
+        [
+            self.position,
+            self.position + Vector2::new(self.size.x, 0.0),
+            self.end(),
+            self.position + Vector2::new(0.0, self.size.y),
+        ]
+    }
+
+    /// Creates the smallest `Rect2` that contains every point in `points`, or `None` if
+    /// `points` is empty.
+    pub(crate) fn from_points(points: impl IntoIterator<Item = Vector2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        let (min, max) = points.fold((first, first), |(min, max), p| {
+            (
+                Vector2::new(min.x.min(p.x), min.y.min(p.y)),
+                Vector2::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+
+        Some(Self::new(min, max - min))
+    }
+}
+
+impl GodotFfi for Rect2 {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
@@ -9,7 +9,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use super::glam_helpers::{GlamConv, GlamType};
-use super::{math::*, Vector2};
+use super::{math::*, Rect2, Vector2};
 
 use super::real_consts::PI;
 use super::{real, RAffine2, RMat2};
@@ -27,6 +27,7 @@ use super::{real, RAffine2, RMat2};
 ///
 /// For methods that don't take translation into account, see [`Basis2D`].
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Transform2D {
     /// The first basis vector.
@@ -111,6 +112,29 @@ impl Transform2D {
         )
     }
 
+    /// Create a new `Transform2D` whose local X axis points from `origin` towards `target`,
+    /// with `origin` as its translation.
+    ///
+    /// Falls back to an unrotated basis if `origin` and `target` coincide, since there's no
+    /// well-defined direction to face in that case.
+    pub fn looking_at(origin: Vector2, target: Vector2) -> Self {
+        let basis = if origin == target {
+            Basis2D::IDENTITY
+        } else {
+            let dir = (target - origin).normalized();
+            Basis2D::from_cols(dir, Vector2::new(-dir.y, dir.x))
+        };
+
+        Self::from_basis_origin(basis, origin)
+    }
+
+    /// Returns the determinant of the transform's basis. A negative value means the transform
+    /// flips orientation (e.g. mirrors along an axis); a value close to zero means the basis is
+    /// singular and [`affine_inverse()`](Self::affine_inverse) won't produce a meaningful result.
+    pub fn determinant(&self) -> real {
+        self.basis().determinant()
+    }
+
     /// Create a reference to the first two columns of the transform
     /// interpreted as a [`Basis2D`].
     fn basis<'a>(&'a self) -> &'a Basis2D {
@@ -267,6 +291,73 @@ impl Transform2D {
         Self::from_cols(self.a, self.b, self.origin + (self.to_basis() * offset))
     }
 
+    /// Applies a rotation by `angle` (in radians) in the parent/global frame, i.e. `self.pre_rotate(angle)`
+    /// is `self.rotated(angle)`.
+    ///
+    /// Thin wrapper over [`rotated()`](Self::rotated), named to read as a pipeline step alongside
+    /// [`post_rotate()`](Self::post_rotate).
+    #[must_use]
+    pub fn pre_rotate(self, angle: real) -> Self {
+        self.rotated(angle)
+    }
+
+    /// Applies a rotation by `angle` (in radians) in the local frame, i.e. `self.post_rotate(angle)`
+    /// is `self.rotated_local(angle)`.
+    ///
+    /// Thin wrapper over [`rotated_local()`](Self::rotated_local), named to read as a pipeline
+    /// step alongside [`pre_rotate()`](Self::pre_rotate).
+    #[must_use]
+    pub fn post_rotate(self, angle: real) -> Self {
+        self.rotated_local(angle)
+    }
+
+    /// Applies a scale by `scale` in the parent/global frame, i.e. `self.pre_scale(scale)` is
+    /// `self.scaled(scale)`.
+    ///
+    /// Thin wrapper over [`scaled()`](Self::scaled), named to read as a pipeline step alongside
+    /// [`post_scale()`](Self::post_scale).
+    #[must_use]
+    pub fn pre_scale(self, scale: Vector2) -> Self {
+        self.scaled(scale)
+    }
+
+    /// Applies a scale by `scale` in the local frame, i.e. `self.post_scale(scale)` is
+    /// `self.scaled_local(scale)`.
+    ///
+    /// Thin wrapper over [`scaled_local()`](Self::scaled_local), named to read as a pipeline step
+    /// alongside [`pre_scale()`](Self::pre_scale).
+    #[must_use]
+    pub fn post_scale(self, scale: Vector2) -> Self {
+        self.scaled_local(scale)
+    }
+
+    /// Applies a translation by `offset` in the parent/global frame, i.e.
+    /// `self.pre_translate(offset)` is `self.translated(offset)`.
+    ///
+    /// Thin wrapper over [`translated()`](Self::translated), named to read as a pipeline step
+    /// alongside [`post_translate()`](Self::post_translate).
+    #[must_use]
+    pub fn pre_translate(self, offset: Vector2) -> Self {
+        self.translated(offset)
+    }
+
+    /// Applies a translation by `offset` in the local frame, i.e. `self.post_translate(offset)`
+    /// is `self.translated_local(offset)`.
+    ///
+    /// Thin wrapper over [`translated_local()`](Self::translated_local), named to read as a
+    /// pipeline step alongside [`pre_translate()`](Self::pre_translate).
+    #[must_use]
+    pub fn post_translate(self, offset: Vector2) -> Self {
+        self.translated_local(offset)
+    }
+
+    /// Composes this transform with `other`, applying `self` first and `other` second --
+    /// equivalent to `other * self`, but named so a chain of transforms reads left to right.
+    #[must_use]
+    pub fn then(self, other: Self) -> Self {
+        other * self
+    }
+
     /// Returns a vector transformed (multiplied) by the basis matrix.
     /// This method does not account for translation (the origin vector).
     ///
@@ -282,6 +373,30 @@ impl Transform2D {
     pub fn basis_xform_inv(&self, v: Vector2) -> Vector2 {
         self.basis().inverse() * v
     }
+
+    /// Transforms `rect`'s four corners and returns the smallest axis-aligned [`Rect2`] that
+    /// contains all of them.
+    ///
+    /// Rotating or skewing a rect generally produces a shape that is no longer axis-aligned, so
+    /// the result is only guaranteed to *contain* the transformed rect, not to match its exact
+    /// outline -- which is exactly what culling or layout code that only understands
+    /// axis-aligned rects needs. To go the other way, transform with
+    /// [`affine_inverse()`](Self::affine_inverse) instead.
+    pub fn transform_rect(&self, rect: Rect2) -> Rect2 {
+        let corners = rect.corners().map(|corner| *self * corner);
+
+        Rect2::from_points(corners).expect("Rect2's 4 corners are never empty")
+    }
+
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(affine: RAffine2) -> Self {
+        <RAffine2 as GlamType>::to_front(&affine)
+    }
+
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RAffine2 {
+        GlamConv::to_glam(&self)
+    }
 }
 
 impl Display for Transform2D {
@@ -362,4 +477,126 @@ impl Basis2D {
     pub(crate) const IDENTITY: Self = Self::from_diagonal(1.0, 1.0);
 
     /// The basis that will flip something along the X axis when used in a
-    /// transformatio
\ No newline at end of file
+    /// transformation.
+    pub(crate) const FLIP_X: Self = Self::from_diagonal(-1.0, 1.0);
+
+    /// The basis that will flip something along the Y axis when used in a
+    /// transformation.
+    pub(crate) const FLIP_Y: Self = Self::from_diagonal(1.0, -1.0);
+
+    const fn from_diagonal(x: real, y: real) -> Self {
+        Self {
+            cols: [Vector2::new(x, 0.0), Vector2::new(0.0, y)],
+        }
+    }
+
+    /// Create a new `Basis2D` from the given column vectors.
+    const fn from_cols(x: Vector2, y: Vector2) -> Self {
+        Self { cols: [x, y] }
+    }
+
+    /// Create a `Basis2D` that will rotate counter-clockwise by `angle` (in radians).
+    fn from_angle(angle: real) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::from_cols(Vector2::new(cos, sin), Vector2::new(-sin, cos))
+    }
+
+    /// The rotation of this basis (in radians).
+    fn rotation(&self) -> real {
+        self.cols[0].y.atan2(self.cols[0].x)
+    }
+
+    fn row_a(&self) -> Vector2 {
+        Vector2::new(self.cols[0].x, self.cols[1].x)
+    }
+
+    fn row_b(&self) -> Vector2 {
+        Vector2::new(self.cols[0].y, self.cols[1].y)
+    }
+
+    fn set_row_a(&mut self, row: Vector2) {
+        self.cols[0].x = row.x;
+        self.cols[1].x = row.y;
+    }
+
+    fn set_row_b(&mut self, row: Vector2) {
+        self.cols[0].y = row.x;
+        self.cols[1].y = row.y;
+    }
+
+    fn determinant(&self) -> real {
+        self.cols[0].x * self.cols[1].y - self.cols[0].y * self.cols[1].x
+    }
+
+    /// The scale of this basis. A negative determinant flips the sign of the second
+    /// column's scale, matching Godot's own `get_scale()`.
+    fn scale(&self) -> Vector2 {
+        let det_sign = if self.determinant() < 0.0 { -1.0 } else { 1.0 };
+        Vector2::new(self.cols[0].length(), det_sign * self.cols[1].length())
+    }
+
+    /// The skew of this basis (in radians).
+    fn skew(&self) -> real {
+        let det_sign = if self.determinant() < 0.0 { -1.0 } else { 1.0 };
+
+        self.cols[0]
+            .normalized()
+            .dot(det_sign * self.cols[1].normalized())
+            .acos()
+            - PI * 0.5
+    }
+
+    /// Returns this basis with its vectors made orthogonal (90 degrees apart) and
+    /// normalized, using Gram-Schmidt orthonormalization.
+    #[must_use]
+    fn orthonormalized(self) -> Self {
+        let x = self.cols[0].normalized();
+        let y = self.cols[1] - x * x.dot(self.cols[1]);
+
+        Self::from_cols(x, y.normalized())
+    }
+
+    #[must_use]
+    fn scaled(mut self, scale: Vector2) -> Self {
+        self.cols[0] *= scale.x;
+        self.cols[1] *= scale.y;
+        self
+    }
+
+    #[must_use]
+    fn inverse(self) -> Self {
+        self.glam(|mat| mat.inverse())
+    }
+
+    /// Converts `self` to the corresponding [`glam`] type.
+    fn to_glam(self) -> RMat2 {
+        GlamConv::to_glam(&self)
+    }
+}
+
+impl Mul<Vector2> for Basis2D {
+    type Output = Vector2;
+
+    fn mul(self, rhs: Vector2) -> Self::Output {
+        self.glam2(&rhs, |mat, vec| mat.mul_vec2(vec))
+    }
+}
+
+impl GlamType for RMat2 {
+    type Mapped = Basis2D;
+
+    fn to_front(&self) -> Self::Mapped {
+        Basis2D::from_cols(
+            Vector2::from_glam(self.x_axis),
+            Vector2::from_glam(self.y_axis),
+        )
+    }
+
+    fn from_front(mapped: &Self::Mapped) -> Self {
+        Self::from_cols(mapped.cols[0].to_glam(), mapped.cols[1].to_glam())
+    }
+}
+
+impl GlamConv for Basis2D {
+    type Glam = RMat2;
+}
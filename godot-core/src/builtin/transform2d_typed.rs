@@ -0,0 +1,156 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// Compile-time coordinate-space tracking for `Transform2D`, in the style of `euclid`'s
+// `Transform2D<T, Src, Dst>`. `Src`/`Dst` are zero-sized marker types chosen by the caller (e.g.
+// `struct World; struct Screen;`); they never appear in the transform's runtime representation, so
+// [`TypedTransform2D`] has the exact same layout and cost as the plain [`Transform2D`] it wraps.
+//
+// This lives in its own file rather than alongside [`Transform2D`] in `transform2d.rs`, matching
+// the split already used for `Array<T, Own>` (`array.rs` + `array_typed.rs`): the generic wrapper
+// and its typed point counterpart are a separate concern from the untyped math they delegate to.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::{real, Transform2D, Vector2};
+
+/// A point in the coordinate space `Space`, carrying no runtime information beyond the
+/// wrapped [`Vector2`] -- `Space` only exists to prevent mixing up points from unrelated
+/// coordinate systems at compile time.
+#[repr(transparent)]
+pub struct Point2<Space> {
+    /// The point's coordinates, ignoring which space they're expressed in.
+    pub vector: Vector2,
+    _space: PhantomData<Space>,
+}
+
+impl<Space> Point2<Space> {
+    /// Creates a new point from its `x` and `y` coordinates.
+    pub const fn new(x: real, y: real) -> Self {
+        Self::from_untyped(Vector2::new(x, y))
+    }
+
+    /// Attaches the `Space` marker to an untyped [`Vector2`].
+    pub const fn from_untyped(vector: Vector2) -> Self {
+        Self {
+            vector,
+            _space: PhantomData,
+        }
+    }
+
+    /// Discards the `Space` marker, returning the underlying [`Vector2`].
+    pub const fn to_untyped(self) -> Vector2 {
+        self.vector
+    }
+}
+
+impl<Space> Clone for Point2<Space> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Space> Copy for Point2<Space> {}
+
+impl<Space> PartialEq for Point2<Space> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vector == other.vector
+    }
+}
+
+impl<Space> fmt::Debug for Point2<Space> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Point2").field(&self.vector).finish()
+    }
+}
+
+/// A [`Transform2D`] whose source and destination coordinate spaces are tracked in its type,
+/// in the style of `euclid`'s `Transform2D<T, Src, Dst>`.
+///
+/// `Src` and `Dst` are marker types chosen by the caller -- typically zero-sized unit structs
+/// like `struct World;` and `struct Screen;` -- and never appear at runtime; `TypedTransform2D`
+/// has the same layout and cost as the plain [`Transform2D`] it wraps. The benefit is purely at
+/// compile time: a `TypedTransform2D<World, Screen>` can be [`then`](Self::then)-composed only
+/// with a transform whose `Src` matches its own `Dst`, which catches accidentally chaining two
+/// transforms that both map *into* screen space instead of one mapping out of it.
+pub struct TypedTransform2D<Src, Dst> {
+    /// The untyped transform this wrapper delegates all of its math to.
+    pub transform: Transform2D,
+    _phantom: PhantomData<(Src, Dst)>,
+}
+
+impl<Src, Dst> TypedTransform2D<Src, Dst> {
+    /// The identity transform, with no translation, rotation or scaling applied.
+    pub const IDENTITY: Self = Self::from_untyped(Transform2D::IDENTITY);
+
+    /// Attaches the `Src`/`Dst` markers to an untyped [`Transform2D`].
+    pub const fn from_untyped(transform: Transform2D) -> Self {
+        Self {
+            transform,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Discards the `Src`/`Dst` markers, returning the underlying [`Transform2D`].
+    pub const fn to_untyped(self) -> Transform2D {
+        self.transform
+    }
+
+    /// Maps `point` from `Src` space to `Dst` space.
+    pub fn transform_point(&self, point: Point2<Src>) -> Point2<Dst> {
+        Point2::from_untyped(self.transform * point.vector)
+    }
+
+    /// Returns the inverse transform, which maps `Dst` space back to `Src` space.
+    ///
+    /// Delegates to [`Transform2D::affine_inverse`], under the same assumption that this
+    /// transform is composed of rotation, scaling and translation.
+    #[must_use]
+    pub fn inverse(self) -> TypedTransform2D<Dst, Src> {
+        TypedTransform2D::from_untyped(self.transform.affine_inverse())
+    }
+
+    /// Composes this transform with `other`, producing a single transform that maps directly
+    /// from `Src` to `Dst2`.
+    ///
+    /// Reads as a left-to-right pipeline: `a.then(b)` first applies `a` (`Src` to `Dst`), then
+    /// `b` (`Dst` to `Dst2`) -- the opposite order from the underlying `Transform2D * Transform2D`
+    /// multiplication, which applies its right-hand side first.
+    #[must_use]
+    pub fn then<Dst2>(self, other: TypedTransform2D<Dst, Dst2>) -> TypedTransform2D<Src, Dst2> {
+        TypedTransform2D::from_untyped(other.transform * self.transform)
+    }
+}
+
+impl<Src, Dst> Clone for TypedTransform2D<Src, Dst> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Src, Dst> Copy for TypedTransform2D<Src, Dst> {}
+
+impl<Src, Dst> PartialEq for TypedTransform2D<Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.transform == other.transform
+    }
+}
+
+impl<Src, Dst> Default for TypedTransform2D<Src, Dst> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<Src, Dst> fmt::Debug for TypedTransform2D<Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TypedTransform2D")
+            .field(&self.transform)
+            .finish()
+    }
+}
@@ -10,7 +10,7 @@ use sys::{ffi_methods, GodotFfi};
 
 use super::glam_helpers::{GlamConv, GlamType};
 use super::{real, RAffine3};
-use super::{Basis, Projection, Vector3};
+use super::{Basis, Projection, Quaternion, Vector3};
 
 /// Affine 3D transform (3x4 matrix).
 ///
@@ -24,6 +24,7 @@ use super::{Basis, Projection, Vector3};
 /// [ a.z  b.z  c.z  o.z ]
 /// ```
 #[derive(Default, Copy, Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Transform3D {
     /// The basis is a matrix containing 3 vectors as its columns. They can be
@@ -99,6 +100,34 @@ impl Transform3D {
         self.glam(|aff| aff.inverse())
     }
 
+    /// Creates a transform from a translation, rotation and (non-negative, per-axis) scale,
+    /// e.g. as commonly stored by glTF and other animation formats.
+    ///
+    /// Inverse of [`decompose()`](Self::decompose).
+    pub fn from_trs(translation: Vector3, rotation: Quaternion, scale: Vector3) -> Self {
+        Self {
+            basis: Basis::from_quat(rotation) * Basis::from_scale(scale),
+            origin: translation,
+        }
+    }
+
+    /// Splits this transform into a translation, rotation and (signed) per-axis scale, e.g. for
+    /// interop with glTF and other animation formats that store nodes as separate TRS values
+    /// rather than a packed matrix.
+    ///
+    /// Inverse of [`from_trs()`](Self::from_trs).
+    pub fn decompose(self) -> (Vector3, Quaternion, Vector3) {
+        let scale = self.basis.scale();
+
+        let rotation_basis = Basis::from_cols(
+            self.basis.col_a() / scale.x,
+            self.basis.col_b() / scale.y,
+            self.basis.col_c() / scale.z,
+        );
+
+        (self.origin, rotation_basis.to_quat(), scale)
+    }
+
     /// Returns a transform interpolated between this transform and another by
     /// a given weight (on the range of 0.0 to 1.0).
     ///
@@ -247,6 +276,92 @@ impl Transform3D {
             origin: self.origin + (self.basis * offset),
         }
     }
+
+    /// Transforms the given point, applying both the basis and the origin translation.
+    ///
+    /// Equivalent to `self * point`.
+    pub fn xform(self, point: Vector3) -> Vector3 {
+        self * point
+    }
+
+    /// Transforms the given direction vector by the basis only, without applying the origin
+    /// translation. Use this instead of [`xform()`](Self::xform) for normals, velocities and
+    /// other free vectors, which shouldn't be affected by translation.
+    ///
+    /// Equivalent to `self.basis * dir`.
+    pub fn xform_normal(self, dir: Vector3) -> Vector3 {
+        self.basis * dir
+    }
+
+    /// Alias for [`xform_normal()`](Self::xform_normal).
+    pub fn basis_xform(self, dir: Vector3) -> Vector3 {
+        self.xform_normal(dir)
+    }
+
+    /// Transforms the given surface normal by the inverse transpose of the basis, without
+    /// applying the origin translation.
+    ///
+    /// Unlike [`xform_normal()`](Self::xform_normal), this correctly keeps a normal perpendicular
+    /// to its surface even if the basis applies non-uniform scale.
+    pub fn xform_inv_normal(self, normal: Vector3) -> Vector3 {
+        let det = self.basis.determinant();
+        if det == 0.0 {
+            return Vector3::ZERO;
+        }
+
+        let col_a = self.basis.col_a();
+        let col_b = self.basis.col_b();
+        let col_c = self.basis.col_c();
+
+        // The inverse-transpose of the basis has `(b×c)/det`, `(c×a)/det` and `(a×b)/det` as its
+        // columns; applying it as a matrix-vector product is then a weighted sum of those columns.
+        let inverse_transpose = Basis::from_cols(
+            col_b.cross(col_c) / det,
+            col_c.cross(col_a) / det,
+            col_a.cross(col_b) / det,
+        );
+
+        inverse_transpose * normal
+    }
+
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(affine: RAffine3) -> Self {
+        <RAffine3 as GlamType>::to_front(&affine)
+    }
+
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RAffine3 {
+        GlamConv::to_glam(&self)
+    }
+
+    /// Transforms every point in `points` in place, converting `self` to its [`glam`] form only
+    /// once rather than on every element like a per-element [`xform()`](Self::xform) call would.
+    pub fn xform_slice(self, points: &mut [Vector3]) {
+        let affine = self.to_glam();
+        for point in points {
+            *point = Vector3::from_glam(affine.transform_point3(point.to_glam()));
+        }
+    }
+
+    /// Like [`xform_slice()`](Self::xform_slice), but returns the transformed points as a new
+    /// `Vec` instead of mutating `points` in place.
+    pub fn xform_collect(self, points: &[Vector3]) -> Vec<Vector3> {
+        let affine = self.to_glam();
+        points
+            .iter()
+            .map(|point| Vector3::from_glam(affine.transform_point3(point.to_glam())))
+            .collect()
+    }
+
+    /// Transforms every direction in `dirs` in place by the basis only, without applying the
+    /// origin translation; see [`xform_normal()`](Self::xform_normal) for the single-element
+    /// version.
+    pub fn basis_xform_slice(self, dirs: &mut [Vector3]) {
+        let affine = self.to_glam();
+        for dir in dirs {
+            *dir = Vector3::from_glam(affine.transform_vector3(dir.to_glam()));
+        }
+    }
 }
 
 impl Display for Transform3D {
@@ -330,4 +445,36 @@ mod test {
     const DUMMY_TRANSFORM: Transform3D = Transform3D::new(
         Basis::from_cols(
             Vector3::new(1.0, 2.0, 3.0),
-            Vector3::ne
\ No newline at end of file
+            Vector3::new(4.0, 5.0, 6.0),
+            Vector3::new(7.0, 8.0, 9.0),
+        ),
+        Vector3::new(10.0, 11.0, 12.0),
+    );
+
+    #[test]
+    fn xform_normal_ignores_translation() {
+        let dir = Vector3::new(0.5, -1.0, 2.0);
+
+        assert_eq!(
+            DUMMY_TRANSFORM.xform_normal(dir),
+            DUMMY_TRANSFORM.basis * dir
+        );
+        assert_eq!(
+            DUMMY_TRANSFORM.basis_xform(dir),
+            DUMMY_TRANSFORM.xform_normal(dir)
+        );
+    }
+
+    #[test]
+    fn xform_inv_normal_matches_rotation_without_scale() {
+        // For a pure rotation, the inverse-transpose of the basis equals the basis itself, so
+        // transforming a normal should agree exactly with transforming a direction.
+        let basis = Basis::from_axis_angle(Vector3::new(1.0, 2.0, 3.0).normalized(), 0.7);
+        let transform = Transform3D::new(basis, Vector3::new(1.0, 0.0, -2.0));
+        let normal = Vector3::new(0.3, 0.6, -0.2).normalized();
+
+        assert!(transform
+            .xform_inv_normal(normal)
+            .is_equal_approx(transform.xform_normal(normal)));
+    }
+}
@@ -0,0 +1,107 @@
+
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+// A shared interface over `Transform2D` and `Transform3D`, in the style of `cgmath`'s `Transform`
+// trait. Lives in its own file rather than being split across `transform2d.rs`/`transform3d.rs`,
+// since it's a cross-cutting concern over both rather than a method that belongs to either type.
+
+use super::{Transform2D, Transform3D, Vector2, Vector3};
+
+/// Common interface for affine transforms, implemented by both [`Transform2D`] and
+/// [`Transform3D`].
+///
+/// Lets generic math or animation code (e.g. interpolating a hierarchy of transforms, or
+/// composing a transform chain) be written once and used at either dimensionality.
+pub trait Transform: Copy + Sized {
+    /// The point type this transform maps, accounting for translation.
+    type Point;
+
+    /// The vector type this transform's basis maps, ignoring translation.
+    type Vector;
+
+    /// The identity transform, with no translation, rotation or scaling applied.
+    fn identity() -> Self;
+
+    /// Combines this transform with `other`, applying `other` first -- i.e. `self.concat(other)`
+    /// transforms a point by first applying `other`, then `self`.
+    #[must_use]
+    fn concat(&self, other: &Self) -> Self;
+
+    /// Returns the inverse of this transform, or `None` if it has no inverse (e.g. because it
+    /// scales some direction to zero, making the basis singular).
+    #[must_use]
+    fn inverse_transform(&self) -> Option<Self>;
+
+    /// Maps `vector` through this transform's basis only, ignoring translation.
+    fn transform_vector(&self, vector: Self::Vector) -> Self::Vector;
+
+    /// Maps `point` through this transform's full basis and translation.
+    fn transform_point(&self, point: Self::Point) -> Self::Point;
+}
+
+impl Transform for Transform2D {
+    type Point = Vector2;
+    type Vector = Vector2;
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn concat(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    fn inverse_transform(&self) -> Option<Self> {
+        // `affine_inverse()` divides by the basis determinant internally; a singular basis
+        // shows up here as a non-finite result, without needing a separate determinant check.
+        let inverse = self.affine_inverse();
+        if inverse.is_finite() {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+
+    fn transform_vector(&self, vector: Self::Vector) -> Self::Vector {
+        self.basis_xform(vector)
+    }
+
+    fn transform_point(&self, point: Self::Point) -> Self::Point {
+        *self * point
+    }
+}
+
+impl Transform for Transform3D {
+    type Point = Vector3;
+    type Vector = Vector3;
+
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+
+    fn concat(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    fn inverse_transform(&self) -> Option<Self> {
+        let inverse = self.affine_inverse();
+        if inverse.is_finite() {
+            Some(inverse)
+        } else {
+            None
+        }
+    }
+
+    fn transform_vector(&self, vector: Self::Vector) -> Self::Vector {
+        let [a, b, c] = self.basis.to_cols();
+        a * vector.x + b * vector.y + c * vector.z
+    }
+
+    fn transform_point(&self, point: Self::Point) -> Self::Point {
+        *self * point
+    }
+}
@@ -106,4 +106,59 @@ macro_rules! impl_variant_traits_int {
             }
         }
     };
-}
\ No newline at end of file
+}
+
+/// Like [`impl_variant_traits_int!`], but for `core::num::NonZero*` integer types.
+///
+/// `to_variant` forwards through the widened `i64` value; `try_from_variant` additionally rejects
+/// zero, since a `NonZero*` can never represent it.
+macro_rules! impl_variant_traits_nonzero_int {
+    ($T:ty) => {
+        impl ToVariant for $T {
+            fn to_variant(&self) -> Variant {
+                i64::from(self.get()).to_variant()
+            }
+        }
+
+        impl FromVariant for $T {
+            fn try_from_variant(v: &Variant) -> Result<Self, VariantConversionError> {
+                let raw = i64::try_from_variant(v)?;
+                let narrowed =
+                    <$T as NonZeroInt>::Repr::try_from(raw).map_err(|_e| VariantConversionError)?;
+
+                <$T>::new(narrowed).ok_or(VariantConversionError)
+            }
+        }
+
+        impl VariantMetadata for $T {
+            fn variant_type() -> VariantType {
+                VariantType::Int
+            }
+        }
+    };
+}
+
+/// Helper to name the underlying integer representation of a `core::num::NonZero*` type, so the
+/// macro above can route through `TryFrom<i64>` generically.
+trait NonZeroInt {
+    type Repr: TryFrom<i64>;
+}
+
+macro_rules! impl_nonzero_int {
+    ($NonZero:ty, $Repr:ty) => {
+        impl NonZeroInt for $NonZero {
+            type Repr = $Repr;
+        }
+
+        impl_variant_traits_nonzero_int!($NonZero);
+    };
+}
+
+impl_nonzero_int!(std::num::NonZeroI8, i8);
+impl_nonzero_int!(std::num::NonZeroI16, i16);
+impl_nonzero_int!(std::num::NonZeroI32, i32);
+impl_nonzero_int!(std::num::NonZeroI64, i64);
+impl_nonzero_int!(std::num::NonZeroU8, u8);
+impl_nonzero_int!(std::num::NonZeroU16, u16);
+impl_nonzero_int!(std::num::NonZeroU32, u32);
+impl_nonzero_int!(std::num::NonZeroU64, u64);
\ No newline at end of file
@@ -24,6 +24,32 @@ pub struct Variant {
 }
 
 impl Variant {
+    /// Returns a pointer to the opaque Godot variant data, for read-only FFI calls.
+    ///
+    /// This is the `Variant`-specific counterpart to [`GodotFfi::sys`][sys::GodotFfi::sys]: `Variant`
+    /// doesn't implement that trait, since its _variant ptr_ encoding is a different pointer type
+    /// (`GDExtensionVariantPtr`) than the _type ptr_ (`GDExtensionTypePtr`) the trait is built around.
+    #[doc(hidden)]
+    pub fn var_sys(&self) -> sys::GDExtensionVariantPtr {
+        &self.opaque as *const OpaqueVariant as sys::GDExtensionVariantPtr
+    }
+
+    /// Constructs a `Variant`, by zero-initializing the opaque storage and letting `init_fn`
+    /// populate it through a _variant ptr_.
+    ///
+    /// # Safety
+    /// `init_fn` must be a function that fully initializes the (possibly-uninitialized) _variant ptr_
+    /// it is given.
+    #[doc(hidden)]
+    pub unsafe fn from_var_sys_init(init_fn: impl FnOnce(sys::GDExtensionVariantPtr)) -> Self {
+        let mut raw = std::mem::MaybeUninit::<OpaqueVariant>::uninit();
+        init_fn(raw.as_mut_ptr() as sys::GDExtensionVariantPtr);
+
+        Self {
+            opaque: raw.assume_init(),
+        }
+    }
+
     /// Create an empty variant (`null` value in GDScript).
     pub fn nil() -> Self {
         Self::default()
@@ -53,6 +79,15 @@ impl Variant {
         T::try_from_variant(self)
     }
 
+    /// Convert to type `T`, applying Godot's implicit coercions (e.g. float -> int, int -> bool)
+    /// instead of requiring an exact type match.
+    ///
+    /// Unlike [`Self::to`]/[`Self::try_to`], this never fails for a coercible pair of types; see
+    /// [`CoerceFromVariant`] for the exact rules each implementation follows.
+    pub fn coerce_to<T: CoerceFromVariant>(&self) -> T {
+        T::coerce_from_variant(self)
+    }
+
     /// Checks whether the variant is empty (`null` value in GDScript).
     ///
     /// See also [`Self::get_type`].
@@ -65,4 +100,363 @@ impl Variant {
     ///
     /// If this variant holds a type `Object` but no instance (represented as a null object pointer), then `Nil` will be returned for
     /// consistency. This may deviate from Godot behavior -- for example, calling `Node::get_node_or_null()` with an invalid
-    /// path returns a variant tha
\ No newline at end of file
+    /// path returns a variant that is typed as `Object` but holds a null value.
+    pub fn get_type(&self) -> VariantType {
+        let sys_type = unsafe { interface_fn!(variant_get_type)(self.var_sys()) };
+
+        // SAFETY: `VariantType` is a `#[repr(i32)]` enum whose discriminants are the
+        // GDExtension variant type tags.
+        unsafe { std::mem::transmute(sys_type) }
+    }
+
+    /// Reads the value currently held by this variant, and converts it to an owned, strongly typed
+    /// Rust value via [`VariantDispatch`].
+    ///
+    /// Since the concrete type is read off [`Self::get_type()`] first, the subsequent conversion is
+    /// guaranteed to succeed (unlike [`Self::try_to()`], which must guess the target type).
+    ///
+    /// This allows writing a single `match v.dispatch() { ... }` instead of a chain of fallible
+    /// conversions.
+    pub fn dispatch(&self) -> VariantDispatch {
+        match self.get_type() {
+            VariantType::Nil => VariantDispatch::Nil,
+            VariantType::Bool => VariantDispatch::Bool(self.to()),
+            VariantType::Int => VariantDispatch::Int(self.to()),
+            VariantType::Float => VariantDispatch::Float(self.to::<f64>()),
+            VariantType::Vector2i => VariantDispatch::Vector2i(self.to()),
+            VariantType::Vector3 => VariantDispatch::Vector3(self.to()),
+            VariantType::Vector3i => VariantDispatch::Vector3i(self.to()),
+            VariantType::Vector4 => VariantDispatch::Vector4(self.to()),
+            VariantType::Vector4i => VariantDispatch::Vector4i(self.to()),
+            VariantType::Color => VariantDispatch::Color(self.to()),
+            VariantType::Basis => VariantDispatch::Basis(self.to()),
+            VariantType::Transform2D => VariantDispatch::Transform2D(self.to()),
+            VariantType::Transform3D => VariantDispatch::Transform3D(self.to()),
+            VariantType::Quaternion => VariantDispatch::Quaternion(self.to()),
+            VariantType::StringName => VariantDispatch::StringName(self.to()),
+            VariantType::Dictionary => VariantDispatch::Dictionary(self.to()),
+            // TODO cover the remaining `VariantType` variants as their builtin types are added.
+            other => VariantDispatch::Unsupported(other),
+        }
+    }
+
+    /// Calls the method `method` on the value held by this variant, the same way GDScript's
+    /// dynamic `.call()` does, returning `Err` instead of panicking if Godot reports the call as
+    /// invalid.
+    ///
+    /// See [`Self::call`] for a panicking version.
+    pub fn try_call(
+        &self,
+        method: impl Into<StringName>,
+        args: &[Variant],
+    ) -> Result<Variant, CallError> {
+        self.call_inner(&method.into(), args)
+    }
+
+    /// ⚠️ Calls the method `method` on the value held by this variant, the same way GDScript's
+    /// dynamic `.call()` does.
+    ///
+    /// # Panics
+    /// If Godot reports the call as invalid (unknown method, wrong argument count/type, ...). Use
+    /// [`Self::try_call`] to handle this case instead of panicking.
+    pub fn call(&self, method: impl Into<StringName>, args: &[Variant]) -> Variant {
+        let method = method.into();
+        self.call_inner(&method, args)
+            .unwrap_or_else(|err| panic!("Variant::call() failed: {err}"))
+    }
+
+    fn call_inner(&self, method: &StringName, args: &[Variant]) -> Result<Variant, CallError> {
+        let arg_ptrs: Vec<sys::GDExtensionConstVariantPtr> = args
+            .iter()
+            .map(|arg| arg.var_sys() as sys::GDExtensionConstVariantPtr)
+            .collect();
+
+        let mut error = sys::GDExtensionCallError {
+            error: sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK,
+            argument: 0,
+            expected: 0,
+        };
+
+        // SAFETY: `self.var_sys()`/`method.string_sys()` are valid pointers for the duration of this
+        // call, `arg_ptrs` stays alive until `variant_call` returns, and `error` is a valid out-param.
+        let result = unsafe {
+            Variant::from_var_sys_init(|return_ptr| {
+                interface_fn!(variant_call)(
+                    self.var_sys(),
+                    method.string_sys(),
+                    arg_ptrs.as_ptr(),
+                    arg_ptrs.len() as i64,
+                    return_ptr,
+                    ptr::addr_of_mut!(error),
+                );
+            })
+        };
+
+        CallError::check(error)?;
+        Ok(result)
+    }
+}
+
+/// Error returned by [`Variant::try_call`] when Godot rejects the call, mirroring the
+/// `GDExtensionCallErrorType` values Godot itself reports for a failed dynamic call.
+#[derive(Copy, Clone, Debug)]
+pub enum CallError {
+    /// The target value has no method by that name.
+    InvalidMethod,
+
+    /// The argument at `index` doesn't match the method's parameter type; `expected` names the type
+    /// the method actually wants (via [`VariantType::metadata`]'s underlying registry).
+    InvalidArgument { index: i32, expected: VariantType },
+
+    /// More arguments were passed than the method accepts.
+    TooManyArguments { index: i32 },
+
+    /// Fewer arguments were passed than the method requires.
+    TooFewArguments { index: i32 },
+
+    /// The variant does not hold a live instance to call a method on.
+    InstanceIsNull,
+
+    /// The method exists, but can't be called in a const context (not applicable to dynamic
+    /// `Variant::call`, but part of Godot's error vocabulary).
+    MethodNotConst,
+}
+
+impl CallError {
+    /// Translates a raw `GDExtensionCallError` into `Ok(())`/`Err(CallError)`.
+    fn check(raw: sys::GDExtensionCallError) -> Result<(), Self> {
+        #[allow(non_upper_case_globals)]
+        let err = match raw.error {
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_OK => return Ok(()),
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_INVALID_METHOD => {
+                Self::InvalidMethod
+            }
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT => {
+                Self::InvalidArgument {
+                    index: raw.argument,
+                    expected: VariantType::from_sys(raw.expected as sys::GDExtensionVariantType),
+                }
+            }
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS => {
+                Self::TooManyArguments {
+                    index: raw.argument,
+                }
+            }
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS => {
+                Self::TooFewArguments {
+                    index: raw.argument,
+                }
+            }
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL => {
+                Self::InstanceIsNull
+            }
+            sys::GDExtensionCallErrorType_GDEXTENSION_CALL_ERROR_METHOD_NOT_CONST => {
+                Self::MethodNotConst
+            }
+            other => unreachable!("unrecognized GDExtensionCallErrorType {other}"),
+        };
+
+        Err(err)
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMethod => write!(f, "method does not exist"),
+            Self::InvalidArgument { index, expected } => write!(
+                f,
+                "argument {index} does not match expected type {expected:?}"
+            ),
+            Self::TooManyArguments { index } => {
+                write!(f, "too many arguments, expected at most {index}")
+            }
+            Self::TooFewArguments { index } => {
+                write!(f, "too few arguments, expected at least {index}")
+            }
+            Self::InstanceIsNull => write!(f, "instance is null"),
+            Self::MethodNotConst => write!(f, "method is not const"),
+        }
+    }
+}
+
+impl std::error::Error for CallError {}
+
+/// Dispatches on the dynamic type of a [`Variant`], yielding an owned, strongly typed Rust value.
+///
+/// Obtained via [`Variant::dispatch()`]. One arm of this enum exists per currently supported
+/// `VariantType` case; types not yet covered fall back to [`VariantDispatch::Unsupported`] rather
+/// than panicking.
+#[non_exhaustive]
+pub enum VariantDispatch {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Vector2i(crate::builtin::Vector2i),
+    Vector3(crate::builtin::Vector3),
+    Vector3i(crate::builtin::Vector3i),
+    Vector4(crate::builtin::Vector4),
+    Vector4i(crate::builtin::Vector4i),
+    Color(crate::builtin::Color),
+    Basis(crate::builtin::Basis),
+    Transform2D(crate::builtin::Transform2D),
+    Transform3D(crate::builtin::Transform3D),
+    Quaternion(crate::builtin::Quaternion),
+    StringName(StringName),
+    Dictionary(crate::builtin::Dictionary),
+    /// Catch-all for variant types that don't yet have a dedicated dispatch case.
+    Unsupported(VariantType),
+}
+
+/// Implements Godot's implicit (lossy) conversions out of a [`Variant`], for use with
+/// [`Variant::coerce_to`].
+///
+/// This complements the strict [`FromVariant`]: where `FromVariant` fails whenever the variant
+/// doesn't already hold exactly `T`, `CoerceFromVariant` instead follows Godot's implicit
+/// number/bool conversions (e.g. when a GDScript `int` is passed where a `float` parameter is
+/// expected). Strings are neither parsed nor produced: a `String` variant coerces like any other
+/// non-numeric value. Each implementation documents exactly which source types it accepts and how
+/// it maps them.
+pub trait CoerceFromVariant: Sized {
+    /// Reads `variant`, coercing its value to `Self` rather than requiring an exact type match.
+    fn coerce_from_variant(variant: &Variant) -> Self;
+}
+
+impl CoerceFromVariant for i64 {
+    /// Int stays as-is; Float truncates towards zero; Bool becomes `0`/`1`; anything else is `0`.
+    fn coerce_from_variant(variant: &Variant) -> Self {
+        match variant.dispatch() {
+            VariantDispatch::Int(i) => i,
+            VariantDispatch::Float(f) => f as i64,
+            VariantDispatch::Bool(b) => b as i64,
+            _ => 0,
+        }
+    }
+}
+
+impl CoerceFromVariant for f64 {
+    /// Float stays as-is; Int is widened; Bool becomes `0.0`/`1.0`; anything else is `0.0`.
+    fn coerce_from_variant(variant: &Variant) -> Self {
+        match variant.dispatch() {
+            VariantDispatch::Float(f) => f,
+            VariantDispatch::Int(i) => i as f64,
+            VariantDispatch::Bool(b) => b as i64 as f64,
+            _ => 0.0,
+        }
+    }
+}
+
+impl CoerceFromVariant for bool {
+    /// Bool stays as-is; any nonzero Int/Float is `true`; `Nil` is `false`; anything else is `true`
+    /// (mirroring Godot's "every non-Nil, non-zero value is truthy" rule).
+    fn coerce_from_variant(variant: &Variant) -> Self {
+        match variant.dispatch() {
+            VariantDispatch::Nil => false,
+            VariantDispatch::Bool(b) => b,
+            VariantDispatch::Int(i) => i != 0,
+            VariantDispatch::Float(f) => f != 0.0,
+            _ => true,
+        }
+    }
+}
+
+/// `serde` support for [`Variant`].
+///
+/// Only the [`VariantDispatch`] arms with a natural serde encoding are covered; serializing any
+/// other variant type fails with a descriptive error instead of panicking, with one exception:
+/// `Object` has no meaningful standalone serialization (round-tripping a live engine object through
+/// e.g. `serde_json` isn't something this crate can support), so it serializes as `Nil` rather than
+/// erroring -- the same "object-by-null" compromise `gdnative`'s serde integration makes. This is
+/// also what backs the recursive (de)serialization of values inside a
+/// [`Dictionary`](crate::builtin::Dictionary).
+///
+/// Note: `Array` and `String` aren't covered here yet. `Array<T>`'s own `Serialize`/`Deserialize`
+/// (see `array.rs`) only go through its already-typed element accessors, never through `Variant`
+/// conversion, because `Array` doesn't implement `FromVariant`/`ToVariant` anywhere in this crate
+/// (nor does `GodotString`, whose defining module is absent entirely) -- both are pre-existing gaps
+/// this change doesn't attempt to paper over.
+#[cfg(feature = "serde")]
+mod serde_support {
+    use super::{Variant, VariantDispatch, VariantType};
+    use crate::builtin::{
+        Basis, Color, Quaternion, ToVariant, Transform2D, Transform3D, Vector2i, Vector3, Vector3i,
+        Vector4, Vector4i,
+    };
+    use serde::ser::Error as SerError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    enum VariantData {
+        Nil,
+        Bool(bool),
+        Int(i64),
+        Float(f64),
+        Vector2i(Vector2i),
+        Vector3(Vector3),
+        Vector3i(Vector3i),
+        Vector4(Vector4),
+        Vector4i(Vector4i),
+        Color(Color),
+        Basis(Basis),
+        Transform2D(Transform2D),
+        Transform3D(Transform3D),
+        Quaternion(Quaternion),
+        Dictionary(crate::builtin::Dictionary),
+    }
+
+    impl Serialize for Variant {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let data = match self.dispatch() {
+                VariantDispatch::Nil => VariantData::Nil,
+                VariantDispatch::Bool(b) => VariantData::Bool(b),
+                VariantDispatch::Int(i) => VariantData::Int(i),
+                VariantDispatch::Float(f) => VariantData::Float(f),
+                VariantDispatch::Vector2i(v) => VariantData::Vector2i(v),
+                VariantDispatch::Vector3(v) => VariantData::Vector3(v),
+                VariantDispatch::Vector3i(v) => VariantData::Vector3i(v),
+                VariantDispatch::Vector4(v) => VariantData::Vector4(v),
+                VariantDispatch::Vector4i(v) => VariantData::Vector4i(v),
+                VariantDispatch::Color(c) => VariantData::Color(c),
+                VariantDispatch::Basis(b) => VariantData::Basis(b),
+                VariantDispatch::Transform2D(t) => VariantData::Transform2D(t),
+                VariantDispatch::Transform3D(t) => VariantData::Transform3D(t),
+                VariantDispatch::Quaternion(q) => VariantData::Quaternion(q),
+                VariantDispatch::Dictionary(d) => VariantData::Dictionary(d),
+                VariantDispatch::Unsupported(VariantType::Object) => VariantData::Nil,
+                _ => {
+                    return Err(S::Error::custom(format!(
+                        "cannot serialize variant of type {:?}",
+                        self.get_type()
+                    )))
+                }
+            };
+
+            data.serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Variant {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let data = VariantData::deserialize(deserializer)?;
+
+            Ok(match data {
+                VariantData::Nil => Variant::nil(),
+                VariantData::Bool(b) => b.to_variant(),
+                VariantData::Int(i) => i.to_variant(),
+                VariantData::Float(f) => f.to_variant(),
+                VariantData::Vector2i(v) => v.to_variant(),
+                VariantData::Vector3(v) => v.to_variant(),
+                VariantData::Vector3i(v) => v.to_variant(),
+                VariantData::Vector4(v) => v.to_variant(),
+                VariantData::Vector4i(v) => v.to_variant(),
+                VariantData::Color(c) => c.to_variant(),
+                VariantData::Basis(b) => b.to_variant(),
+                VariantData::Transform2D(t) => t.to_variant(),
+                VariantData::Transform3D(t) => t.to_variant(),
+                VariantData::Quaternion(q) => q.to_variant(),
+                VariantData::Dictionary(d) => d.to_variant(),
+            })
+        }
+    }
+}
\ No newline at end of file
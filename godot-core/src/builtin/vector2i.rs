@@ -1,4 +1,3 @@
-
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
@@ -22,6 +21,7 @@ use crate::builtin::Vector2;
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`] if 64-bit values are
 /// needed.
 #[derive(Debug, Default, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vector2i {
     /// The vector's X component.
@@ -76,6 +76,58 @@ impl Vector2i {
     fn to_glam(self) -> glam::IVec2 {
         glam::IVec2::new(self.x, self.y)
     }
+
+    /// Returns a new vector with each component clamped between the components of `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.coord_clamp(min, max)
+    }
+
+    /// Returns a new vector with the component-wise minimum of `self` and `with`.
+    pub fn min(self, with: Self) -> Self {
+        self.coord_min(with)
+    }
+
+    /// Returns a new vector with the component-wise maximum of `self` and `with`.
+    pub fn max(self, with: Self) -> Self {
+        self.coord_max(with)
+    }
+
+    /// Returns a new vector with each component snapped to the closest multiple of the
+    /// corresponding component of `step`. A zero component of `step` leaves that axis untouched.
+    pub fn snapped(self, step: Self) -> Self {
+        fn snapped_axis(value: i32, step: i32) -> i32 {
+            if step != 0 {
+                (value as f64 / step as f64 + 0.5).floor() as i32 * step
+            } else {
+                value
+            }
+        }
+
+        Self::new(snapped_axis(self.x, step.x), snapped_axis(self.y, step.y))
+    }
+
+    /// Returns the squared length of this vector, promoted to `i64` to avoid overflow.
+    pub fn length_squared(self) -> i64 {
+        let x = self.x as i64;
+        let y = self.y as i64;
+
+        x * x + y * y
+    }
+
+    /// Returns the length of this vector.
+    pub fn length(self) -> f64 {
+        (self.length_squared() as f64).sqrt()
+    }
+
+    /// Returns the squared distance between this vector and `to`.
+    pub fn distance_squared_to(self, to: Self) -> i64 {
+        (to - self).length_squared()
+    }
+
+    /// Returns the distance between this vector and `to`.
+    pub fn distance_to(self, to: Self) -> f64 {
+        (to - self).length()
+    }
 }
 
 /// Formats the vector like Godot: `(x, y)`.
@@ -88,6 +140,7 @@ impl fmt::Display for Vector2i {
 impl_common_vector_fns!(Vector2i, i32);
 impl_vector_operators!(Vector2i, i32, (x, y));
 impl_vector_index!(Vector2i, i32, (x, y), Vector2iAxis, (X, Y));
+impl_vector_swizzle2!(Vector2i, Vector2i, xy => (x, y), yx => (y, x));
 
 impl GodotFfi for Vector2i {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
@@ -105,4 +158,4 @@ pub enum Vector2iAxis {
 
 impl GodotFfi for Vector2iAxis {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
-}
\ No newline at end of file
+}
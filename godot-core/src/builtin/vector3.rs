@@ -12,7 +12,7 @@ use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
 use crate::builtin::math::*;
-use crate::builtin::Vector3i;
+use crate::builtin::{Basis, Vector3i};
 
 use super::glam_helpers::GlamConv;
 use super::glam_helpers::GlamType;
@@ -28,6 +28,7 @@ use super::{real, RVec3};
 /// vectors; use the gdext library with the `double-precision` feature in that case.
 ///
 /// See [`Vector3i`] for its integer counterpart.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 #[repr(C)]
 pub struct Vector3 {
@@ -39,6 +40,10 @@ pub struct Vector3 {
     pub z: real,
 }
 
+impl_vector_operators!(Vector3, real, (x, y, z));
+impl_float_vector_fns!(Vector3, real);
+impl_vector_index!(Vector3, real, (x, y, z), Vector3Axis, (X, Y, Z));
+
 impl Vector3 {
     /// Vector with all components set to `0.0`.
     pub const ZERO: Self = Self::splat(0.0);
@@ -83,13 +88,13 @@ impl Vector3 {
         }
     }
 
-    /// Converts the corresponding `glam` type to `Self`.
-    fn from_glam(v: RVec3) -> Self {
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(v: RVec3) -> Self {
         Self::new(v.x, v.y, v.z)
     }
 
-    /// Converts `self` to the corresponding `glam` type.
-    fn to_glam(self) -> RVec3 {
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RVec3 {
         RVec3::new(self.x, self.y, self.z)
     }
 
@@ -121,10 +126,12 @@ impl Vector3 {
         Self::from_glam(self.to_glam().ceil())
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn clamp(self, min: Self, max: Self) -> Self {
         Self::from_glam(self.to_glam().clamp(min.to_glam(), max.to_glam()))
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn cross(self, with: Self) -> Self {
         Self::from_glam(self.to_glam().cross(with.to_glam()))
     }
@@ -173,6 +180,7 @@ impl Vector3 {
         (to - self).length()
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn dot(self, with: Self) -> real {
         self.to_glam().dot(with.to_glam())
     }
@@ -185,12 +193,6 @@ impl Vector3 {
         Self::new(1.0 / self.x, 1.0 / self.y, 1.0 / self.z)
     }
 
-    pub fn is_equal_approx(self, to: Self) -> bool {
-        is_equal_approx(self.x, to.x)
-            && is_equal_approx(self.y, to.y)
-            && is_equal_approx(self.z, to.z)
-    }
-
     pub fn is_finite(self) -> bool {
         self.to_glam().is_finite()
     }
@@ -199,14 +201,16 @@ impl Vector3 {
         self.to_glam().is_normalized()
     }
 
-    pub fn is_zero_approx(self) -> bool {
-        is_zero_approx(self.x) && is_zero_approx(self.y) && is_zero_approx(self.z)
+    pub fn length(self) -> real {
+        self.length_squared().sqrt()
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn length_squared(self) -> real {
         self.to_glam().length_squared()
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn lerp(self, to: Self, weight: real) -> Self {
         Self::from_glam(self.to_glam().lerp(to.to_glam(), weight))
     }
@@ -243,6 +247,7 @@ impl Vector3 {
         }
     }
 
+    #[cfg(feature = "double-precision")]
     pub fn move_toward(self, to: Self, delta: real) -> Self {
         let vd = to - self;
         let len = vd.length();
@@ -253,10 +258,246 @@ impl Vector3 {
         }
     }
 
+    pub fn normalized(self) -> Self {
+        self / self.length()
+    }
+
+    /// Returns the outer product (`self ⊗ with`) of this vector and `with`, as a 3x3 matrix.
+    ///
+    /// _Godot equivalent: `Vector3.outer`_
+    pub fn outer(self, with: Self) -> Basis {
+        Basis::from_rows(with * self.x, with * self.y, with * self.z)
+    }
+
     pub fn posmod(self, pmod: real) -> Self {
         Self::new(
             fposmod(self.x, pmod),
             fposmod(self.y, pmod),
             fposmod(self.z, pmod),
         )
-    }
\ No newline at end of file
+    }
+
+    /// Returns the vector projected onto the line spanned by `onto`.
+    ///
+    /// _Godot equivalent: `Vector3.project`_
+    pub fn project(self, onto: Self) -> Self {
+        onto * (self.dot(onto) / onto.length_squared())
+    }
+
+    /// Returns the vector reflected off a plane with the given (normalized) `normal`.
+    ///
+    /// _Godot equivalent: `Vector3.reflect`_
+    pub fn reflect(self, normal: Self) -> Self {
+        normal * 2.0 * self.dot(normal) - self
+    }
+
+    /// Rotates this vector by `angle` radians around `axis`, which must be normalized.
+    ///
+    /// Uses [Rodrigues' rotation formula](https://en.wikipedia.org/wiki/Rodrigues%27_rotation_formula).
+    ///
+    /// _Godot equivalent: `Vector3.rotated`_
+    pub fn rotated(self, axis: Self, angle: real) -> Self {
+        let (sin, cos) = angle.sin_cos();
+
+        self * cos + axis.cross(self) * sin + axis * axis.dot(self) * (1.0 - cos)
+    }
+
+    /// Returns the vector slid along a plane with the given (normalized) `normal`.
+    ///
+    /// _Godot equivalent: `Vector3.slide`_
+    pub fn slide(self, normal: Self) -> Self {
+        self - normal * self.dot(normal)
+    }
+
+    pub const fn xyz(self) -> Self {
+        Self::new(self.x, self.y, self.z)
+    }
+
+    pub const fn xzy(self) -> Self {
+        Self::new(self.x, self.z, self.y)
+    }
+
+    pub const fn yxz(self) -> Self {
+        Self::new(self.y, self.x, self.z)
+    }
+
+    pub const fn yzx(self) -> Self {
+        Self::new(self.y, self.z, self.x)
+    }
+
+    pub const fn zxy(self) -> Self {
+        Self::new(self.z, self.x, self.y)
+    }
+
+    pub const fn zyx(self) -> Self {
+        Self::new(self.z, self.y, self.x)
+    }
+}
+
+/// SIMD fast path for the hot vector-math methods, via glam's 16-byte-aligned `Vec3A`.
+///
+/// `Vec3A` has no `double-precision` (`f64`) equivalent in `glam`, so this only exists for the
+/// default single-precision build; the `double-precision` build falls back to the unaligned
+/// `to_glam`/`from_glam` (`DVec3`) versions of these methods above.
+///
+/// `Vector3` itself stays `#[repr(C)]` -- `Vec3A` is only ever constructed at the boundary of
+/// these calls, it's not how `Vector3` is stored.
+#[cfg(not(feature = "double-precision"))]
+impl Vector3 {
+    /// Converts to glam's SIMD-aligned vector type.
+    fn to_glam_simd(self) -> glam::Vec3A {
+        glam::Vec3A::new(self.x, self.y, self.z)
+    }
+
+    /// Converts from glam's SIMD-aligned vector type.
+    fn from_glam_simd(v: glam::Vec3A) -> Self {
+        Self::new(v.x, v.y, v.z)
+    }
+
+    pub fn dot(self, with: Self) -> real {
+        self.to_glam_simd().dot(with.to_glam_simd())
+    }
+
+    pub fn cross(self, with: Self) -> Self {
+        Self::from_glam_simd(self.to_glam_simd().cross(with.to_glam_simd()))
+    }
+
+    pub fn length_squared(self) -> real {
+        self.to_glam_simd().length_squared()
+    }
+
+    pub fn lerp(self, to: Self, weight: real) -> Self {
+        Self::from_glam_simd(self.to_glam_simd().lerp(to.to_glam_simd(), weight))
+    }
+
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        Self::from_glam_simd(self.to_glam_simd().clamp(min.to_glam_simd(), max.to_glam_simd()))
+    }
+
+    pub fn move_toward(self, to: Self, delta: real) -> Self {
+        let vd = to - self;
+        let len = vd.to_glam_simd().length();
+        if len <= delta || len < CMP_EPSILON {
+            to
+        } else {
+            self + vd / len * delta
+        }
+    }
+}
+
+/// Enumerates the axes in a [`Vector3`].
+#[derive(Copy, Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[repr(i32)]
+pub enum Vector3Axis {
+    /// The X axis.
+    X,
+    /// The Y axis.
+    Y,
+    /// The Z axis.
+    Z,
+}
+
+impl Vector3Axis {
+    /// Returns the unit vector pointing along this axis, following Godot's `RIGHT`/`UP`/`BACK`
+    /// convention.
+    pub fn as_vector(self) -> Vector3 {
+        match self {
+            Self::X => Vector3::RIGHT,
+            Self::Y => Vector3::UP,
+            Self::Z => Vector3::BACK,
+        }
+    }
+}
+
+impl GodotFfi for Vector3Axis {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::builtin::ApproxEq;
+
+    #[test]
+    fn index_matches_components() {
+        let mut v = Vector3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v[Vector3Axis::X], v.x);
+        assert_eq!(v[Vector3Axis::Y], v.y);
+        assert_eq!(v[Vector3Axis::Z], v.z);
+
+        v[Vector3Axis::Y] = 5.0;
+        assert_eq!(v.y, 5.0);
+    }
+
+    #[test]
+    fn as_vector_maps_to_unit_direction() {
+        assert_eq!(Vector3Axis::X.as_vector(), Vector3::RIGHT);
+        assert_eq!(Vector3Axis::Y.as_vector(), Vector3::UP);
+        assert_eq!(Vector3Axis::Z.as_vector(), Vector3::BACK);
+    }
+
+    #[test]
+    fn max_axis_index_is_indexable() {
+        let v = Vector3::new(1.0, 5.0, 2.0);
+        assert_eq!(v[v.max_axis_index()], v.y);
+    }
+
+    #[test]
+    fn reflect_is_involutive_across_a_plane() {
+        let normal = Vector3::UP;
+        let v = Vector3::new(1.0, -2.0, 3.0);
+
+        assert_eq!(v.reflect(normal), Vector3::new(-1.0, -2.0, -3.0));
+        assert_eq!(v.reflect(normal).reflect(normal), v);
+    }
+
+    #[test]
+    fn slide_removes_the_normal_component() {
+        let normal = Vector3::UP;
+        let v = Vector3::new(1.0, 2.0, 3.0);
+
+        assert_eq!(v.slide(normal).dot(normal), 0.0);
+    }
+
+    #[test]
+    fn project_onto_matches_manual_formula() {
+        let onto = Vector3::new(2.0, 0.0, 0.0);
+        let v = Vector3::new(1.0, 5.0, -3.0);
+
+        assert_eq!(v.project(onto), Vector3::new(1.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn rotated_by_full_turn_is_identity() {
+        let v = Vector3::new(1.0, 2.0, 3.0);
+        let axis = Vector3::UP;
+
+        let rotated = v.rotated(axis, std::f32::consts::TAU as real);
+        assert!(rotated.is_equal_approx(v));
+    }
+
+    #[cfg(not(feature = "double-precision"))]
+    #[test]
+    fn simd_fast_path_matches_scalar_math() {
+        let a = Vector3::new(1.0, 2.0, 3.0);
+        let b = Vector3::new(4.0, -1.0, 0.5);
+
+        assert_eq!(a.dot(b), a.to_glam().dot(b.to_glam()));
+        assert_eq!(a.cross(b), Vector3::from_glam(a.to_glam().cross(b.to_glam())));
+        assert_eq!(a.length_squared(), a.to_glam().length_squared());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_test {
+    use super::*;
+
+    #[test]
+    fn serde_roundtrip() {
+        let vector = Vector3::new(1.0, 2.0, 3.0);
+        let json = serde_json::to_string(&vector).unwrap();
+
+        assert_eq!(vector, serde_json::from_str(&json).unwrap());
+    }
+}
\ No newline at end of file
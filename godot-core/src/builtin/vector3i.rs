@@ -21,6 +21,7 @@ use crate::builtin::Vector3;
 /// required. Note that the values are limited to 32 bits, and unlike [`Vector3`] this cannot be
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`] if 64-bit values are
 /// needed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Default, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
 #[repr(C)]
 pub struct Vector3i {
@@ -85,6 +86,63 @@ impl Vector3i {
     fn to_glam(self) -> glam::IVec3 {
         glam::IVec3::new(self.x, self.y, self.z)
     }
+
+    /// Returns a new vector with each component clamped between the components of `min` and `max`.
+    pub fn clamp(self, min: Self, max: Self) -> Self {
+        self.coord_clamp(min, max)
+    }
+
+    /// Returns a new vector with the component-wise minimum of `self` and `with`.
+    pub fn min(self, with: Self) -> Self {
+        self.coord_min(with)
+    }
+
+    /// Returns a new vector with the component-wise maximum of `self` and `with`.
+    pub fn max(self, with: Self) -> Self {
+        self.coord_max(with)
+    }
+
+    /// Returns a new vector with each component snapped to the closest multiple of the
+    /// corresponding component of `step`. A zero component of `step` leaves that axis untouched.
+    pub fn snapped(self, step: Self) -> Self {
+        fn snapped_axis(value: i32, step: i32) -> i32 {
+            if step != 0 {
+                (value as f64 / step as f64 + 0.5).floor() as i32 * step
+            } else {
+                value
+            }
+        }
+
+        Self::new(
+            snapped_axis(self.x, step.x),
+            snapped_axis(self.y, step.y),
+            snapped_axis(self.z, step.z),
+        )
+    }
+
+    /// Returns the squared length of this vector, promoted to `i64` to avoid overflow.
+    pub fn length_squared(self) -> i64 {
+        let x = self.x as i64;
+        let y = self.y as i64;
+        let z = self.z as i64;
+
+        x * x + y * y + z * z
+    }
+
+    /// Returns the length of this vector.
+    pub fn length(self) -> f64 {
+        (self.length_squared() as f64).sqrt()
+    }
+
+    /// Returns the squared distance between this vector and `to`.
+    pub fn distance_squared_to(self, to: Self) -> i64 {
+        (to - self).length_squared()
+    }
+
+    /// Returns the distance between this vector and `to`.
+    pub fn distance_to(self, to: Self) -> f64 {
+        (to - self).length()
+    }
 }
 
 /// Formats the vector like Godot: `(x, y, z)`.
@@ -98,6 +156,19 @@ impl_common_vector_fns!(Vector3i, i32);
 impl_vector_operators!(Vector3i, i32, (x, y, z));
 impl_vector_index!(Vector3i, i32, (x, y, z), Vector3iAxis, (X, Y, Z));
 
+impl_vector_swizzle2!(
+    Vector3i, Vector2i,
+    xy => (x, y), yx => (y, x),
+    xz => (x, z), zx => (z, x),
+    yz => (y, z), zy => (z, y),
+);
+impl_vector_swizzle3!(
+    Vector3i, Vector3i,
+    xyz => (x, y, z), xzy => (x, z, y),
+    yxz => (y, x, z), yzx => (y, z, x),
+    zxy => (z, x, y), zyx => (z, y, x),
+);
+
 impl GodotFfi for Vector3i {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
@@ -1,4 +1,3 @@
-
 /*
  * This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
@@ -25,6 +24,7 @@ use super::{real, RVec4};
 ///
 /// See [`Vector4i`] for its integer counterpart.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vector4 {
     /// The vector's X component.
@@ -40,6 +40,18 @@ pub struct Vector4 {
 impl_vector_operators!(Vector4, real, (x, y, z, w));
 impl_vector_index!(Vector4, real, (x, y, z, w), Vector4Axis, (X, Y, Z, W));
 impl_common_vector_fns!(Vector4, real);
+
+impl_vector_swizzle3!(
+    Vector4, Vector3,
+    xyz => (x, y, z), zyx => (z, y, x),
+    xyw => (x, y, w), wyx => (w, y, x),
+    xzw => (x, z, w), wzx => (w, z, x),
+    yzw => (y, z, w), wzy => (w, z, y),
+);
+impl_vector_swizzle4!(
+    Vector4,
+    xyzw => (x, y, z, w), wzyx => (w, z, y, x),
+);
 impl_float_vector_fns!(Vector4, real);
 
 impl Vector4 {
@@ -72,13 +84,13 @@ impl Vector4 {
     /// Infinity vector, a vector with all components set to `real::INFINITY`.
     pub const INF: Self = Self::splat(real::INFINITY);
 
-    /// Converts the corresponding `glam` type to `Self`.
-    fn from_glam(v: RVec4) -> Self {
+    /// Converts the corresponding [`glam`] type to `Self`.
+    pub fn from_glam(v: RVec4) -> Self {
         Self::new(v.x, v.y, v.z, v.w)
     }
 
-    /// Converts `self` to the corresponding `glam` type.
-    fn to_glam(self) -> RVec4 {
+    /// Converts `self` to the corresponding [`glam`] type.
+    pub fn to_glam(self) -> RVec4 {
         RVec4::new(self.x, self.y, self.z, self.w)
     }
 }
@@ -126,4 +138,4 @@ impl GlamType for RVec4 {
 
 impl GlamConv for Vector4 {
     type Glam = RVec4;
-}
\ No newline at end of file
+}
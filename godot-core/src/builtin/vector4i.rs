@@ -20,6 +20,7 @@ use crate::builtin::Vector4;
 /// configured with an engine build option. Use `i64` or [`PackedInt64Array`] if 64-bit values are
 /// needed.
 #[derive(Debug, Default, Clone, Copy, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Vector4i {
     /// The vector's X component.
@@ -36,6 +37,27 @@ impl_vector_operators!(Vector4i, i32, (x, y, z, w));
 impl_vector_index!(Vector4i, i32, (x, y, z, w), Vector4iAxis, (X, Y, Z, W));
 impl_common_vector_fns!(Vector4i, i32);
 
+impl_vector_swizzle2!(
+    Vector4i, Vector2i,
+    xy => (x, y), yx => (y, x),
+    xz => (x, z), zx => (z, x),
+    xw => (x, w), wx => (w, x),
+    yz => (y, z), zy => (z, y),
+    yw => (y, w), wy => (w, y),
+    zw => (z, w), wz => (w, z),
+);
+impl_vector_swizzle3!(
+    Vector4i, Vector3i,
+    xyz => (x, y, z), zyx => (z, y, x),
+    xyw => (x, y, w), wyx => (w, y, x),
+    xzw => (x, z, w), wzx => (w, z, x),
+    yzw => (y, z, w), wzy => (w, z, y),
+);
+impl_vector_swizzle4!(
+    Vector4i,
+    xyzw => (x, y, z, w), wzyx => (w, z, y, x),
+);
+
 impl Vector4i {
     /// Returns a `Vector4i` with the given components.
     pub const fn new(x: i32, y: i32, z: i32, w: i32) -> Self {
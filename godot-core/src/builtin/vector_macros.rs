@@ -62,4 +62,228 @@ macro_rules! impl_vector_vector_binary_operator {
 /// Implements a component-wise single infix binary operator between a vector on the left and a
 /// scalar on the right-hand side.
 macro_rules! impl_vector_scalar_binary_operator {
-    (
\ No newline at end of file
+    (
+        // Name of the vector type.
+        $Vector:ty,
+        // Type of each individual component, for example `i32`.
+        $Scalar:ty,
+        // Names of the components, with parentheses, for example `(x, y)`.
+        ($($components:ident),*),
+        // Name of the operator trait, for example `Mul`.
+        $Operator:ident,
+        // Name of the function on the operator trait, for example `mul`.
+        $func:ident
+    ) => {
+        impl std::ops::$Operator<$Scalar> for $Vector {
+            type Output = Self;
+            fn $func(mut self, rhs: $Scalar) -> Self::Output {
+                $(
+                    self.$components = self.$components.$func(rhs);
+                )*
+                self
+            }
+        }
+    }
+}
+
+/// Implements `Add`, `Sub`, `Mul`/`Div` (both vector-vector and vector-scalar), `AddAssign`,
+/// `SubAssign`, vector-scalar `MulAssign`/`DivAssign`, and `Neg` for a vector type, in terms of the
+/// lower-level operator macros above.
+macro_rules! impl_vector_operators {
+    ($Vector:ty, $Scalar:ty, ($($components:ident),*)) => {
+        impl_vector_vector_binary_operator!($Vector, $Scalar, ($($components),*), Add, add);
+        impl_vector_vector_binary_operator!($Vector, $Scalar, ($($components),*), Sub, sub);
+        impl_vector_vector_binary_operator!($Vector, $Scalar, ($($components),*), Mul, mul);
+        impl_vector_vector_binary_operator!($Vector, $Scalar, ($($components),*), Div, div);
+
+        impl_vector_scalar_binary_operator!($Vector, $Scalar, ($($components),*), Mul, mul);
+        impl_vector_scalar_binary_operator!($Vector, $Scalar, ($($components),*), Div, div);
+
+        impl_vector_unary_operator!($Vector, $Scalar, ($($components),*), Neg, neg);
+
+        impl std::ops::AddAssign for $Vector {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl std::ops::SubAssign for $Vector {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl std::ops::MulAssign<$Scalar> for $Vector {
+            fn mul_assign(&mut self, rhs: $Scalar) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl std::ops::DivAssign<$Scalar> for $Vector {
+            fn div_assign(&mut self, rhs: $Scalar) {
+                *self = *self / rhs;
+            }
+        }
+    }
+}
+
+/// Implements `Index`/`IndexMut` by a per-type axis enum, so e.g. `v[Vector3Axis::Y]` reads/writes
+/// the matching field.
+macro_rules! impl_vector_index {
+    (
+        $Vector:ty,
+        $Scalar:ty,
+        ($($components:ident),*),
+        $Axis:ty,
+        ($($axis_variants:ident),*)
+    ) => {
+        impl std::ops::Index<$Axis> for $Vector {
+            type Output = $Scalar;
+
+            fn index(&self, axis: $Axis) -> &Self::Output {
+                match axis {
+                    $(<$Axis>::$axis_variants => &self.$components,)*
+                }
+            }
+        }
+
+        impl std::ops::IndexMut<$Axis> for $Vector {
+            fn index_mut(&mut self, axis: $Axis) -> &mut Self::Output {
+                match axis {
+                    $(<$Axis>::$axis_variants => &mut self.$components,)*
+                }
+            }
+        }
+    }
+}
+
+/// Implements the handful of component-wise methods (`abs`, `sign`, `coord_min`, `coord_max`, `coord_clamp`) shared
+/// between the integer and floating-point vector types, for a specific `$Vector` type.
+macro_rules! impl_common_vector_fns {
+    (Vector2i, $Scalar:ty) => {
+        impl_common_vector_fns!(@@ Vector2i, $Scalar, (x, y));
+    };
+    (Vector3i, $Scalar:ty) => {
+        impl_common_vector_fns!(@@ Vector3i, $Scalar, (x, y, z));
+    };
+    (Vector4i, $Scalar:ty) => {
+        impl_common_vector_fns!(@@ Vector4i, $Scalar, (x, y, z, w));
+    };
+    (Vector4, $Scalar:ty) => {
+        impl_common_vector_fns!(@@ Vector4, $Scalar, (x, y, z, w));
+    };
+
+    (@@ $Vector:ty, $Scalar:ty, ($($components:ident),*)) => {
+        impl $Vector {
+            /// Returns a new vector with each component set to its absolute value.
+            pub fn abs(self) -> Self {
+                Self::new($(self.$components.abs()),*)
+            }
+
+            /// Returns a new vector with each component set to its sign (`-1`, `0`, or `1`).
+            pub fn sign(self) -> Self {
+                fn sign_of(value: $Scalar) -> $Scalar {
+                    if value > Default::default() {
+                        1 as $Scalar
+                    } else if value < Default::default() {
+                        -1 as $Scalar
+                    } else {
+                        Default::default()
+                    }
+                }
+
+                Self::new($(sign_of(self.$components)),*)
+            }
+
+            /// Returns a new vector with the component-wise minimum of `self` and `other`.
+            pub fn coord_min(self, other: Self) -> Self {
+                Self::new($(self.$components.min(other.$components)),*)
+            }
+
+            /// Returns a new vector with the component-wise maximum of `self` and `other`.
+            pub fn coord_max(self, other: Self) -> Self {
+                Self::new($(self.$components.max(other.$components)),*)
+            }
+
+            /// Returns a new vector with each component clamped between the matching components of
+            /// `min` and `max`.
+            pub fn coord_clamp(self, min: Self, max: Self) -> Self {
+                Self::new($(self.$components.clamp(min.$components, max.$components)),*)
+            }
+        }
+    };
+}
+
+/// Implements [`ApproxEq`](crate::builtin::ApproxEq) for a floating-point vector type, by folding
+/// the scalar `is_equal_approx`/`is_zero_approx` helpers over each component.
+macro_rules! impl_float_vector_fns {
+    (Vector3, $Scalar:ty) => {
+        impl_float_vector_fns!(@@ Vector3, $Scalar, (x, y, z));
+    };
+    (Vector4, $Scalar:ty) => {
+        impl_float_vector_fns!(@@ Vector4, $Scalar, (x, y, z, w));
+    };
+
+    (@@ $Vector:ty, $Scalar:ty, ($($components:ident),*)) => {
+        impl crate::builtin::ApproxEq for $Vector {
+            fn is_equal_approx(self, other: Self) -> bool {
+                $(crate::builtin::math::is_equal_approx(self.$components, other.$components))&&*
+            }
+
+            fn is_zero_approx(self) -> bool {
+                $(crate::builtin::math::is_zero_approx(self.$components))&&*
+            }
+        }
+    };
+}
+
+/// Implements a list of named 2-component swizzle accessors on `$Vector`, each returning a
+/// `$Output`.
+///
+/// Invoked with `name => (field_a, field_b)` pairs, for example `xy => (x, y)`.
+macro_rules! impl_vector_swizzle2 {
+    ($Vector:ty, $Output:ty, $( $name:ident => ($a:ident, $b:ident) ),* $(,)?) => {
+        impl $Vector {
+            $(
+                #[doc = concat!("Returns the 2-component swizzle `", stringify!($name), "`.")]
+                pub const fn $name(self) -> $Output {
+                    <$Output>::new(self.$a, self.$b)
+                }
+            )*
+        }
+    };
+}
+
+/// Implements a list of named 3-component swizzle accessors on `$Vector`, each returning a
+/// `$Output`.
+///
+/// Invoked with `name => (field_a, field_b, field_c)` pairs, for example `zyx => (z, y, x)`.
+macro_rules! impl_vector_swizzle3 {
+    ($Vector:ty, $Output:ty, $( $name:ident => ($a:ident, $b:ident, $c:ident) ),* $(,)?) => {
+        impl $Vector {
+            $(
+                #[doc = concat!("Returns the 3-component swizzle `", stringify!($name), "`.")]
+                pub const fn $name(self) -> $Output {
+                    <$Output>::new(self.$a, self.$b, self.$c)
+                }
+            )*
+        }
+    };
+}
+
+/// Implements a list of named 4-component swizzle accessors on `$Vector`, each returning `Self`.
+///
+/// Invoked with `name => (field_a, field_b, field_c, field_d)` pairs, for example
+/// `wzyx => (w, z, y, x)`.
+macro_rules! impl_vector_swizzle4 {
+    ($Vector:ty, $( $name:ident => ($a:ident, $b:ident, $c:ident, $d:ident) ),* $(,)?) => {
+        impl $Vector {
+            $(
+                #[doc = concat!("Returns the 4-component swizzle `", stringify!($name), "`.")]
+                pub const fn $name(self) -> Self {
+                    Self::new(self.$a, self.$b, self.$c, self.$d)
+                }
+            )*
+        }
+    };
+}
@@ -137,4 +137,8 @@ impl ExtensionLayer for DefaultLayer {
         crate::auto_register_classes();
     }
 
-    fn deinitialize(
\ No newline at end of file
+    fn deinitialize(&mut self) {
+        #[cfg(feature = "trace")]
+        crate::storage::diagnostics::assert_all_destroyed();
+    }
+}
\ No newline at end of file
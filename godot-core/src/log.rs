@@ -4,20 +4,185 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::sync::Mutex;
+
+/// Severity of a single [`LogRecord`], in increasing order.
+///
+/// The derived [`Ord`] relies on declaration order below matching this severity order, so that
+/// filtering ("drop everything below level X") is a plain `record.level < min_level` comparison.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+    ScriptError,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::ScriptError => "script_error",
+        }
+    }
+}
+
+/// A single log event, as passed to an installed [`LogSink`].
+pub struct LogRecord<'a> {
+    pub level: LogLevel,
+    pub message: &'a str,
+    pub file: &'static str,
+    pub line: u32,
+}
+
+/// Receives [`LogRecord`]s emitted through the `godot_warn!`/`godot_error!`/`godot_script_error!`
+/// macros.
+///
+/// Install one with [`set_log_sink`]. A sink must be prepared to be called concurrently, since
+/// Godot can invoke extension code from multiple threads.
+pub trait LogSink: Send + Sync {
+    fn emit(&self, record: &LogRecord);
+}
+
+/// The sink used when none has been installed: forwards straight to Godot's own
+/// `print_warning`/`print_error`/`print_script_error`, exactly as the macros did before this
+/// module existed.
+struct DefaultSink;
+
+impl LogSink for DefaultSink {
+    fn emit(&self, record: &LogRecord) {
+        // SAFETY: `msg`/`func`/`file` are NUL-terminated byte strings, as `interface_fn!` requires.
+        unsafe {
+            let msg = format!("{}\0", record.message);
+            let file = format!("{}\0", record.file);
+
+            match record.level {
+                LogLevel::Warn => {
+                    crate::sys::interface_fn!(print_warning)(
+                        msg.as_bytes().as_ptr() as *const _,
+                        "<function unset>\0".as_bytes().as_ptr() as *const _,
+                        file.as_bytes().as_ptr() as *const _,
+                        record.line as _,
+                        false as crate::sys::GDExtensionBool,
+                    );
+                }
+                LogLevel::Error => {
+                    crate::sys::interface_fn!(print_error)(
+                        msg.as_bytes().as_ptr() as *const _,
+                        "<function unset>\0".as_bytes().as_ptr() as *const _,
+                        file.as_bytes().as_ptr() as *const _,
+                        record.line as _,
+                        false as crate::sys::GDExtensionBool,
+                    );
+                }
+                LogLevel::ScriptError => {
+                    crate::sys::interface_fn!(print_script_error)(
+                        msg.as_bytes().as_ptr() as *const _,
+                        "<function unset>\0".as_bytes().as_ptr() as *const _,
+                        file.as_bytes().as_ptr() as *const _,
+                        record.line as _,
+                        false as crate::sys::GDExtensionBool,
+                    );
+                }
+                // `godot_print!` doesn't route through this sink; see its macro definition below.
+                LogLevel::Info => {}
+            }
+        }
+    }
+}
+
+/// Sink that writes one JSON object per record to stdout, for tools that tail the process log and
+/// parse it programmatically instead of reading the human-oriented console output.
+///
+/// Only `level`, `msg`, `file` and `line` are encoded; this intentionally stays a minimal,
+/// dependency-free encoder rather than pulling in a JSON crate for four fields.
+pub struct JsonLineSink;
+
+impl LogSink for JsonLineSink {
+    fn emit(&self, record: &LogRecord) {
+        println!(
+            "{{\"level\":\"{}\",\"msg\":{},\"file\":{},\"line\":{}}}",
+            record.level.as_str(),
+            json_quote(record.message),
+            json_quote(record.file),
+            record.line,
+        );
+    }
+}
+
+/// Minimal JSON string-literal encoder (quotes + escapes `"`, `\` and control characters).
+fn json_quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+static LOG_SINK: Mutex<Option<Box<dyn LogSink>>> = Mutex::new(None);
+static MIN_LOG_LEVEL: Mutex<LogLevel> = Mutex::new(LogLevel::Info);
+
+/// Installs `sink` as the destination for all subsequent `godot_warn!`/`godot_error!`/
+/// `godot_script_error!` records, replacing the default (direct Godot console output).
+///
+/// Pass [`JsonLineSink`] for machine-readable output, or a custom [`LogSink`] impl to forward
+/// records elsewhere (a file, a telemetry pipe, ...).
+pub fn set_log_sink(sink: impl LogSink + 'static) {
+    *LOG_SINK.lock().unwrap() = Some(Box::new(sink));
+}
+
+/// Restores the default sink (direct Godot console output).
+pub fn reset_log_sink() {
+    *LOG_SINK.lock().unwrap() = None;
+}
+
+/// Sets the minimum [`LogLevel`] that reaches the installed sink; records below it are dropped
+/// before the sink ever sees them. For example, [`LogLevel::Error`] lets errors through and drops
+/// warnings.
+pub fn set_min_log_level(level: LogLevel) {
+    *MIN_LOG_LEVEL.lock().unwrap() = level;
+}
+
+#[doc(hidden)]
+pub fn emit_record(level: LogLevel, message: &str, file: &'static str, line: u32) {
+    if level < *MIN_LOG_LEVEL.lock().unwrap() {
+        return;
+    }
+
+    let record = LogRecord {
+        level,
+        message,
+        file,
+        line,
+    };
+
+    match &*LOG_SINK.lock().unwrap() {
+        Some(sink) => sink.emit(&record),
+        None => DefaultSink.emit(&record),
+    }
+}
+
 #[macro_export]
 macro_rules! godot_warn {
     ($fmt:literal $(, $args:expr)* $(,)?) => {
-        unsafe {
-            let msg = format!("{}\0", format_args!($fmt $(, $args)*));
-
-            $crate::sys::interface_fn!(print_warning)(
-                msg.as_bytes().as_ptr() as *const _,
-                "<function unset>\0".as_bytes().as_ptr() as *const _,
-                concat!(file!(), "\0").as_ptr() as *const _,
-                line!() as _,
-                false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
-            );
-        }
+        $crate::log::emit_record(
+            $crate::log::LogLevel::Warn,
+            &format!($fmt $(, $args)*),
+            file!(),
+            line!(),
+        )
     };
 }
 
@@ -26,34 +191,24 @@ macro_rules! godot_error {
     // FIXME expr needs to be parenthesised, see usages
     ($fmt:literal $(, $args:expr)* $(,)?) => {
     //($($args:tt),* $(,)?) => {
-        unsafe {
-            let msg = format!("{}\0", format_args!($fmt $(, $args)*));
-
-            $crate::sys::interface_fn!(print_error)(
-                msg.as_bytes().as_ptr() as *const _,
-                "<function unset>\0".as_bytes().as_ptr() as *const _,
-                concat!(file!(), "\0").as_ptr() as *const _,
-                line!() as _,
-                false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
-            );
-        }
+        $crate::log::emit_record(
+            $crate::log::LogLevel::Error,
+            &format!($fmt $(, $args)*),
+            file!(),
+            line!(),
+        )
     };
 }
 
 #[macro_export]
 macro_rules! godot_script_error {
     ($fmt:literal $(, $args:expr)* $(,)?) => {
-        unsafe {
-            let msg = format!("{}\0", format_args!($fmt $(, $args)*));
-
-            $crate::sys::interface_fn!(print_script_error)(
-                msg.as_bytes().as_ptr() as *const _,
-                "<function unset>\0".as_bytes().as_ptr() as *const _,
-                concat!(file!(), "\0").as_ptr() as *const _,
-                line!() as _,
-                false as $crate::sys::GDExtensionBool, // whether to create a toast notification in editor
-            );
-        }
+        $crate::log::emit_record(
+            $crate::log::LogLevel::ScriptError,
+            &format!($fmt $(, $args)*),
+            file!(),
+            line!(),
+        )
     };
 }
 
@@ -63,4 +218,9 @@ macro_rules! godot_print {
         $crate::log::print(&[
             $crate::builtin::Variant::from(
                 $crate::builtin::GodotString::from(
-                    forma
\ No newline at end of file
+                    format!($fmt $(, $args)*)
+                )
+            )
+        ])
+    };
+}
@@ -20,7 +20,7 @@ use crate::builtin::{FromVariant, ToVariant, Variant, VariantConversionError};
 use crate::obj::dom::Domain as _;
 use crate::obj::mem::Memory as _;
 use crate::obj::{cap, dom, mem, GodotClass, Inherits, Share};
-use crate::obj::{GdMut, GdRef, InstanceId};
+use crate::obj::{GdBorrowError, GdMut, GdRef, InstanceId};
 use crate::storage::InstanceStorage;
 use crate::{callbacks, engine, out};
 
@@ -138,7 +138,8 @@ where
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
     // Note: possible names: write/read, hold/hold_mut, r/w, r/rw, ...
     pub fn bind(&self) -> GdRef<T> {
-        GdRef::from_cell(self.storage().get())
+        self.try_bind()
+            .unwrap_or_else(|err| panic!("Gd::bind() failed: {err}"))
     }
 
     /// Hands out a guard for an exclusive borrow, through which the user instance can be read and written.
@@ -153,7 +154,28 @@ where
     /// * If there is an ongoing function call from GDScript to Rust, which currently holds a `&T` or `&mut T`
     ///   reference to the user instance. This can happen through re-entrancy (Rust -> GDScript -> Rust call).
     pub fn bind_mut(&mut self) -> GdMut<T> {
-        GdMut::from_cell(self.storage().get_mut())
+        self.try_bind_mut()
+            .unwrap_or_else(|err| panic!("Gd::bind_mut() failed: {err}"))
+    }
+
+    /// Hands out a guard for a shared borrow, or a [`GdBorrowError`] if the user instance is
+    /// currently mutably bound elsewhere, or is being/has been destroyed.
+    ///
+    /// This is the non-panicking counterpart to [`Self::bind()`][Gd::<T>::bind]. Useful for
+    /// reentrant engine callbacks -- e.g. a signal handler that (directly or transitively) touches
+    /// the same object it was invoked on -- where a conflicting borrow should be handled gracefully
+    /// instead of panicking.
+    pub fn try_bind(&self) -> Result<GdRef<T>, GdBorrowError> {
+        self.storage().try_get().map(GdRef::from_cell)
+    }
+
+    /// Hands out a guard for an exclusive borrow, or a [`GdBorrowError`] if the user instance is
+    /// currently bound elsewhere, or is being/has been destroyed.
+    ///
+    /// This is the non-panicking counterpart to [`Self::bind_mut()`][Gd::<T>::bind_mut]. See
+    /// [`Self::try_bind()`][Gd::<T>::try_bind] for when this is useful.
+    pub fn try_bind_mut(&mut self) -> Result<GdMut<T>, GdBorrowError> {
+        self.storage().try_get_mut().map(GdMut::from_cell)
     }
 
     /// Storage object associated with the extension instance
@@ -178,4 +200,227 @@ where
 }
 
 /// _The methods in this impl block are available for any `T`._ <br><br>
-impl<T: GodotClass> Gd<T> {
\ No newline at end of file
+impl<T: GodotClass> Gd<T> {
+    /// Returns the instance ID of this object, or `None` if the object no longer exists (i.e. has been freed).
+    ///
+    /// In contrast to [`Self::instance_id()`], this method never panics and lets you explicitly handle the case
+    /// where the underlying object was destroyed.
+    pub fn try_instance_id(&self) -> Option<InstanceId> {
+        let known_id = self.cached_instance_id.get()?;
+
+        // The cached ID is only ever set once we have observed a valid object; if the engine no longer
+        // resolves it to a live object, the `Gd` has outlived its target.
+        unsafe {
+            let ptr = interface_fn!(object_get_instance_from_id)(known_id.to_u64());
+            (!ptr.is_null()).then_some(known_id)
+        }
+    }
+
+    /// Returns the instance ID of this object.
+    ///
+    /// # Panics
+    /// If this `Gd` points to an already destroyed instance. Use [`Self::try_instance_id()`] if you are not
+    /// sure whether the object is still alive.
+    pub fn instance_id(&self) -> InstanceId {
+        self.try_instance_id().unwrap_or_else(|| {
+            panic!(
+                "failed to call instance_id() on destroyed object; \
+                use try_instance_id() if you expect the object to potentially be dead"
+            )
+        })
+    }
+
+    /// Returns `true` if the object is still alive and has not yet been destroyed.
+    ///
+    /// Equivalent to `self.try_instance_id().is_some()`.
+    pub fn is_instance_valid(&self) -> bool {
+        self.try_instance_id().is_some()
+    }
+
+    /// Creates a weak reference to this object, which does not keep it alive.
+    ///
+    /// Mirrors Godot's `WeakRef`, but is statically typed and dedicated to `Gd` pointers. Call
+    /// [`WeakGd::upgrade()`] to attempt to recover a strong `Gd<T>` reference.
+    pub fn downgrade(&self) -> WeakGd<T> {
+        WeakGd {
+            instance_id: self.try_instance_id(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Non-panicking equality check.
+    ///
+    /// Returns `None` if either `self` or `other` points to an already destroyed object, in which case equality
+    /// cannot be meaningfully established. Otherwise, behaves like [`PartialEq::eq()`].
+    pub fn eq_checked(&self, other: &Gd<T>) -> Option<bool> {
+        let lhs = self.try_instance_id()?;
+        let rhs = other.try_instance_id()?;
+
+        Some(lhs == rhs)
+    }
+
+    /// Non-panicking inequality check; the logical negation of [`Self::eq_checked()`].
+    pub fn ne_checked(&self, other: &Gd<T>) -> Option<bool> {
+        self.eq_checked(other).map(|is_eq| !is_eq)
+    }
+
+    /// Destroys the manually-managed Godot object.
+    ///
+    /// # Panics
+    /// If the object is already destroyed. Whether the object is reference-counted is not checked.
+    pub fn free(self) {
+        let id = self.instance_id();
+        unsafe {
+            interface_fn!(object_destroy)(self.obj_sys());
+        }
+        out!("Gd::free(): freed object {id}");
+    }
+
+    #[doc(hidden)]
+    pub fn obj_sys(&self) -> sys::GDExtensionObjectPtr {
+        // Safety: `opaque` has the same layout as `GDExtensionObjectPtr` (see static size assertion above).
+        unsafe { std::mem::transmute_copy(&self.opaque) }
+    }
+
+    /// Emits the given signal on this object with the given arguments.
+    ///
+    /// This is the untyped, stringly-named counterpart used internally by the generated
+    /// [`Signal::emit()`][crate::obj::Signal::emit] methods that `#[signal]` produces. Prefer those
+    /// where available, since they check the argument count and types at compile time.
+    pub fn emit_signal(&mut self, signal_name: impl Into<crate::builtin::StringName>, varargs: &[Variant]) {
+        let signal_name = signal_name.into();
+        let arg_ptrs: Vec<sys::GDExtensionConstVariantPtr> = varargs
+            .iter()
+            .map(|arg| arg.var_sys() as sys::GDExtensionConstVariantPtr)
+            .collect();
+
+        unsafe {
+            interface_fn!(object_emit_signal)(
+                self.obj_sys(),
+                signal_name.string_sys(),
+                arg_ptrs.as_ptr(),
+                arg_ptrs.len() as i32,
+            );
+        }
+    }
+}
+
+/// A type-checked handle to a Godot signal, generated for each `#[signal]` method inside a
+/// `#[godot_api]` impl block.
+///
+/// Marshals its arguments into [`Variant`]s and emits them through [`Gd::emit_signal()`], so callers
+/// get compile-time-checked argument counts and types instead of a stringly-typed `emit_signal()` call.
+pub struct Signal<T: GodotClass, Ps> {
+    target: Gd<T>,
+    name: crate::builtin::StringName,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<T: GodotClass, Ps> Signal<T, Ps> {
+    #[doc(hidden)]
+    pub fn new(target: Gd<T>, name: impl Into<crate::builtin::StringName>) -> Self {
+        Self {
+            target,
+            name: name.into(),
+            _signature: PhantomData,
+        }
+    }
+}
+
+macro_rules! impl_signal_emit {
+    ($(($Pn:ident, $n:tt)),*) => {
+        impl<T: GodotClass, $($Pn: ToVariant,)*> Signal<T, ($($Pn,)*)> {
+            /// Emits the signal, converting each argument to a [`Variant`].
+            #[allow(clippy::too_many_arguments, unused_variables, clippy::unused_unit)]
+            pub fn emit(&mut self, $($Pn: $Pn,)*) {
+                let args = [$($Pn.to_variant(),)*];
+                self.target.emit_signal(self.name.clone(), &args);
+            }
+        }
+    };
+}
+
+impl_signal_emit!();
+impl_signal_emit!((P0, 0));
+impl_signal_emit!((P0, 0), (P1, 1));
+impl_signal_emit!((P0, 0), (P1, 1), (P2, 2));
+impl_signal_emit!((P0, 0), (P1, 1), (P2, 2), (P3, 3));
+
+impl<T: GodotClass> Share for Gd<T> {
+    fn share(&self) -> Self {
+        Self {
+            opaque: self.opaque,
+            cached_instance_id: std::cell::Cell::new(self.cached_instance_id.get()),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: GodotClass> PartialEq for Gd<T> {
+    /// Compares two `Gd` pointers by instance ID, i.e. whether they point to the same Godot object.
+    ///
+    /// # Panics
+    /// If either operand points to an already destroyed object. Use [`Self::eq_checked()`] to avoid this.
+    fn eq(&self, other: &Self) -> bool {
+        self.instance_id() == other.instance_id()
+    }
+}
+
+impl<T: GodotClass> Eq for Gd<T> {}
+
+impl<T: GodotClass> Debug for Gd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let id = self.cached_instance_id.get();
+        match id {
+            Some(id) => write!(f, "Gd {{ id: {id}, class: {} }}", T::CLASS_NAME),
+            None => write!(f, "Gd {{ freed obj }}"),
+        }
+    }
+}
+
+impl<T: GodotClass> Display for Gd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let id = self.instance_id();
+        write!(f, "<{}#{id}>", T::CLASS_NAME)
+    }
+}
+
+/// A weak, non-owning reference to a [`Gd<T>`], mirroring Godot's `WeakRef`.
+///
+/// Holding a `WeakGd<T>` does not keep the underlying object alive and never participates in its memory
+/// management (neither reference counting nor manual destruction). Use [`WeakGd::upgrade()`] to attempt to
+/// obtain a strong [`Gd<T>`] reference, which returns `None` once the object has been destroyed.
+///
+/// Obtain a `WeakGd<T>` via [`Gd::downgrade()`].
+pub struct WeakGd<T: GodotClass> {
+    instance_id: Option<InstanceId>,
+    _marker: PhantomData<*const T>,
+}
+
+impl<T: GodotClass> WeakGd<T> {
+    /// Attempts to upgrade this weak reference to a strong [`Gd<T>`].
+    ///
+    /// Returns `None` if the referenced object has since been destroyed.
+    pub fn upgrade(&self) -> Option<Gd<T>> {
+        let id = self.instance_id?;
+        Gd::try_from_instance_id(id)
+    }
+}
+
+impl<T: GodotClass> Clone for WeakGd<T> {
+    fn clone(&self) -> Self {
+        Self {
+            instance_id: self.instance_id,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: GodotClass> Debug for WeakGd<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.instance_id {
+            Some(id) => write!(f, "WeakGd {{ id: {id}, class: {} }}", T::CLASS_NAME),
+            None => write!(f, "WeakGd {{ freed obj }}"),
+        }
+    }
+}
\ No newline at end of file
@@ -9,6 +9,12 @@ use std::cell;
 use std::fmt::Debug;
 use std::ops::{Deref, DerefMut};
 
+/// Error returned by [`Gd::try_bind()`][crate::obj::Gd::try_bind] and
+/// [`Gd::try_bind_mut()`][crate::obj::Gd::try_bind_mut] when the user instance can't be borrowed
+/// right now -- either because it's already borrowed in a conflicting way, or because it has been
+/// (or is being) destroyed.
+pub use crate::storage::BorrowError as GdBorrowError;
+
 /// Immutably/shared bound reference guard for a [`Gd`][crate::obj::Gd] smart pointer.
 ///
 /// See [`Gd::bind`][crate::obj::Gd::bind] for usage.
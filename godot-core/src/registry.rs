@@ -14,7 +14,7 @@ use godot_ffi as sys;
 use sys::interface_fn;
 
 use crate::bind::GodotExt;
-use crate::builtin::meta::ClassName;
+use crate::builtin::meta::{ClassName, RpcConfig};
 use crate::builtin::StringName;
 use crate::out;
 use std::any::Any;
@@ -62,6 +62,15 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Whether `#[class(tool)]` was present -- i.e. whether this class should also run inside
+        /// the editor, as opposed to only while the game is running.
+        is_tool: bool,
+
+        /// Whether `#[class(abstract)]` was present -- i.e. whether this class may only be used
+        /// as a base for other registered classes, and should reject direct instantiation
+        /// (`ClassName.new()` from GDScript, or "create node" in the editor).
+        is_abstract: bool,
     },
 
     /// Collected from `#[godot_api] impl MyClass`
@@ -70,6 +79,15 @@ pub enum PluginComponent {
         ///
         /// Always present since that's the entire point of this `impl` block.
         generated_register_fn: ErasedRegisterFn,
+
+        /// `(method_name, default_thunk)` pairs for `#[func(virtual)]`-annotated methods.
+        ///
+        /// Each such method becomes overridable from a `Script` attached to an instance of this
+        /// class, the same way GDScript base-class virtuals are. `default_thunk` is the
+        /// dispatcher the engine should fall back to calling as long as no attached script
+        /// overrides the method -- it's built with the same thunk-generation macro used for
+        /// engine virtuals (see `PluginComponent::UserVirtuals::get_virtual_fn`).
+        virtual_method_binds: Vec<(&'static str, sys::GDExtensionClassCallVirtual)>,
     },
 
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
@@ -99,6 +117,14 @@ pub enum PluginComponent {
             p_name: sys::GDExtensionConstStringNamePtr,
         ) -> sys::GDExtensionClassCallVirtual,
     },
+
+    /// Collected from `#[func(rpc = ...)]`-annotated methods inside `#[godot_api] impl MyClass`.
+    ///
+    /// Each entry pairs a method name with the [`RpcConfig`] the proc macro parsed out of its
+    /// `rpc = ...` attribute; applying them is equivalent to GDScript's `@rpc(...)` annotation.
+    UserRpcBinds {
+        rpc_configs: Vec<(&'static str, RpcConfig)>,
+    },
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -110,6 +136,38 @@ struct ClassRegistrationInfo {
     generated_register_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
     godot_params: sys::GDExtensionClassCreationInfo,
+
+    /// Whether this class should also be constructible inside the editor (`#[class(tool)]`).
+    ///
+    /// On Godot 4.3+, this is surfaced to the engine natively: non-tool classes are registered as
+    /// "runtime classes", which the engine itself refuses to instantiate in the editor. On earlier
+    /// versions, `register_class_raw` instead skips registering the class at all while running
+    /// inside the editor, emulating the same restriction from our side.
+    is_tool: bool,
+
+    /// Whether this class may only be used as a base for other registered classes
+    /// (`#[class(abstract)]`).
+    ///
+    /// An abstract class is allowed to have no `create_instance_func` -- see
+    /// [`auto_register_classes`], which validates that only abstract classes end up without one.
+    is_abstract: bool,
+
+    /// RPC configuration for `#[func(rpc = ...)]`-annotated methods, keyed by method name.
+    ///
+    /// Applied to each new instance at creation time (before it first becomes reachable from
+    /// GDScript) by calling `Node::rpc_config()` once per entry -- see
+    /// [`PluginComponent::UserRpcBinds`].
+    rpc_configs: Vec<(&'static str, RpcConfig)>,
+
+    /// `(method_name, default_thunk)` pairs for `#[func(virtual)]`-annotated methods -- see
+    /// [`PluginComponent::UserMethodBinds::virtual_method_binds`].
+    ///
+    /// Godot looks up a class's virtual methods (engine ones and these) by name through
+    /// `get_virtual_func`, which is currently only wired up to dispatch the engine's own set of
+    /// virtuals (`callbacks::get_virtual`). Making it additionally check here -- falling back to
+    /// `default_thunk` when no attached script overrides `method_name` -- is part of that
+    /// callback's implementation, not yet present in this tree.
+    user_virtual_binds: Vec<(&'static str, sys::GDExtensionClassCallVirtual)>,
 }
 
 /// Registers a class with static type information.
@@ -128,6 +186,14 @@ pub fn register_class<T: GodotExt + cap::GodotInit + cap::ImplementsGodotExt>()
         get_virtual_func: Some(callbacks::get_virtual::<T>),
         get_rid_func: None,
         class_userdata: ptr::null_mut(), // will be passed to create fn, but global per class
+
+        // On hot reload (extension library unloaded and reloaded), Godot uses this to re-create
+        // a Rust instance in place against an engine object it's keeping alive, via
+        // `InstanceStorage::rebind`, rather than freeing and losing it. Only present on GDExtension
+        // ABIs that expose the slot.
+        #[cfg(since_api = "4.3")]
+        recreate_instance_func: Some(callbacks::recreate::<T>),
+
         ..default_creation_info()
     };
 
@@ -139,6 +205,10 @@ pub fn register_class<T: GodotExt + cap::GodotInit + cap::ImplementsGodotExt>()
             raw: callbacks::register_class_by_builder::<T>,
         }),
         godot_params,
+        is_tool: false,
+        is_abstract: false,
+        rpc_configs: Vec::new(),
+        user_virtual_binds: Vec::new(),
     });
 }
 
@@ -166,7 +236,26 @@ pub fn auto_register_classes() {
 
     //out!("Class-map: {map:#?}");
 
-    for info in map.into_values() {
+    for info in map.values() {
+        if !info.is_abstract && info.godot_params.create_instance_func.is_none() {
+            panic!(
+                "class '{}' has no create function and isn't marked #[class(abstract)] -- \
+                 either add #[class(init)] (or a user-defined `init`), or mark it abstract if \
+                 it's only meant to be used as a base class",
+                info.class_name
+            );
+        }
+    }
+
+    // Godot requires a class's parent to already be registered by the time the class itself is
+    // registered, but `map`'s iteration order is arbitrary (hash-based) -- sort parents before
+    // their children first.
+    let mut map = map;
+    for class_name in topological_class_order(&map) {
+        let info = map
+            .remove(&class_name)
+            .expect("topological_class_order() only yields keys present in map");
+
         out!("Register class:   {}", info.class_name);
         register_class_raw(info);
     }
@@ -174,6 +263,69 @@ pub fn auto_register_classes() {
     out!("All classes auto-registered.");
 }
 
+/// Returns `map`'s keys ordered so that every class comes after its `parent_class_name`, provided
+/// that parent is itself a key in `map` (i.e. also auto-registered this run). A `parent_class_name`
+/// that's absent from `map` is assumed to be an already-registered engine class, and the class is
+/// treated as a root.
+///
+/// Panics, naming the offending class(es), on an inheritance cycle among `map`'s classes.
+///
+/// Note: this can't additionally detect a genuinely *dangling* parent -- a `#[derive(GodotClass)]`
+/// base that was meant to be auto-registered but, for whatever reason (feature-gated out, plugin
+/// entry never linked in), never showed up in `map` -- since nothing in this module has a list of
+/// legitimate engine class names to tell that case apart from "parent is a normal engine class".
+/// That would require the generated class table `godot-codegen` produces, which isn't part of
+/// this crate's hand-written sources.
+fn topological_class_order(map: &HashMap<ClassName, ClassRegistrationInfo>) -> Vec<ClassName> {
+    #[derive(Copy, Clone, PartialEq)]
+    enum Mark {
+        Visiting,
+        Done,
+    }
+
+    fn visit(
+        class_name: &ClassName,
+        map: &HashMap<ClassName, ClassRegistrationInfo>,
+        marks: &mut HashMap<ClassName, Mark>,
+        sorted: &mut Vec<ClassName>,
+    ) {
+        match marks.get(class_name) {
+            Some(Mark::Done) => return,
+            Some(Mark::Visiting) => {
+                panic!(
+                    "cycle detected in class inheritance, involving '{}' -- a class cannot \
+                     (transitively) inherit from itself",
+                    class_name
+                );
+            }
+            None => {}
+        }
+
+        marks.insert(class_name.clone(), Mark::Visiting);
+
+        if let Some(parent) = map
+            .get(class_name)
+            .and_then(|info| info.parent_class_name.as_ref())
+        {
+            if map.contains_key(parent) {
+                visit(parent, map, marks, sorted);
+            }
+        }
+
+        marks.insert(class_name.clone(), Mark::Done);
+        sorted.push(class_name.clone());
+    }
+
+    let mut marks = HashMap::new();
+    let mut sorted = Vec::with_capacity(map.len());
+
+    for class_name in map.keys() {
+        visit(class_name, map, &mut marks, &mut sorted);
+    }
+
+    sorted
+}
+
 /// Populate `c` with all the relevant data from `component` (depending on component type).
 fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
     // out!("|   reg (before):    {c:?}");
@@ -183,19 +335,30 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             base_class_name,
             generated_create_fn,
             free_fn,
+            is_tool,
+            is_abstract,
         } => {
             c.parent_class_name = Some(ClassName::from_static(base_class_name));
+            // `generated_create_fn` is legitimately `None` here for an abstract `ClassDef` (no
+            // #[class(init)]) -- `fill_into` already tolerates a `None` source, it only panics on
+            // conflicting `Some`s.
             fill_into(
                 &mut c.godot_params.create_instance_func,
                 generated_create_fn,
             );
             c.godot_params.free_instance_func = Some(free_fn);
+            c.is_tool = is_tool;
+            c.is_abstract = is_abstract;
         }
 
         PluginComponent::UserMethodBinds {
             generated_register_fn,
+            virtual_method_binds,
         } => {
             c.generated_register_fn = Some(generated_register_fn);
+            for (method_name, default_thunk) in virtual_method_binds {
+                fill_keyed_vec(&mut c.user_virtual_binds, method_name, default_thunk);
+            }
         }
 
         PluginComponent::UserVirtuals {
@@ -209,6 +372,12 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             c.godot_params.to_string_func = user_to_string_fn;
             c.godot_params.get_virtual_func = Some(get_virtual_fn);
         }
+
+        PluginComponent::UserRpcBinds { rpc_configs } => {
+            for (method_name, rpc_config) in rpc_configs {
+                fill_keyed_vec(&mut c.rpc_configs, method_name, rpc_config);
+            }
+        }
     }
     // out!("|   reg (after):     {c:?}");
     // out!();
@@ -223,4 +392,17 @@ fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) {
     }
 }
 
+/// Adds `(key, value)` to `dst`, analogous to [`fill_into`]: panics if `key` already has an
+/// entry, since that means two attributes (or a duplicate `impl` block) tried to configure the
+/// same method differently. Used to accumulate both [`PluginComponent::UserRpcBinds`]' RPC
+/// configs and [`PluginComponent::UserMethodBinds`]' virtual-method default thunks, keyed by
+/// method name.
+fn fill_keyed_vec<V>(dst: &mut Vec<(&'static str, V)>, key: &'static str, value: V) {
+    if dst.iter().any(|(k, _)| *k == key) {
+        panic!("entry for method '{key}' already filled");
+    }
+
+    dst.push((key, value));
+}
+
 /// Registers a cl
\ No newline at end of file
@@ -10,6 +10,10 @@ use godot_ffi as sys;
 
 use std::any::type_name;
 use std::cell;
+use std::fmt;
+
+#[cfg(feature = "trace")]
+use diagnostics::on_storage_event;
 
 /// Manages storage and lifecycle of user's extension class instances.
 pub struct InstanceStorage<T: GodotClass> {
@@ -27,11 +31,52 @@ pub enum Lifecycle {
     Dead, // reading this would typically already be too late, only best-effort in case of UB
 }
 
+/// Error returned by [`InstanceStorage::try_get`]/[`try_get_mut`] when the user instance can't be
+/// borrowed right now.
+#[derive(Copy, Clone, Debug)]
+pub enum BorrowError {
+    /// The instance is already borrowed in a way that conflicts with the requested access --
+    /// typically a `GDScript -> Rust -> GDScript -> Rust` reentrancy cycle.
+    AlreadyBound { type_name: &'static str },
+
+    /// Short-circuited before touching the `RefCell`: the instance is being destroyed or has
+    /// already been destroyed by Godot, so no borrow -- even a successful one -- would be useful.
+    Destroyed {
+        type_name: &'static str,
+        lifecycle: Lifecycle,
+    },
+}
+
+impl fmt::Display for BorrowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyBound { type_name } => write!(
+                f,
+                "already bound; T = {type_name}.\n  \
+                 Make sure there is no conflicting &T/&mut T live at the time.\n  \
+                 This often occurs when calling a GDScript function/signal from Rust, which then calls again into Rust code.",
+            ),
+            Self::Destroyed {
+                type_name,
+                lifecycle,
+            } => write!(
+                f,
+                "T = {type_name} is being destroyed or has already been destroyed by Godot (lifecycle: {lifecycle:?})",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BorrowError {}
+
 /// For all Godot extension classes
 impl<T: GodotClass> InstanceStorage<T> {
     pub fn construct(user_instance: T) -> Self {
         out!("    Storage::construct             <{}>", type_name::<T>());
 
+        #[cfg(feature = "trace")]
+        on_storage_event(type_name::<T>(), diagnostics::StorageEvent::Constructed);
+
         Self {
             user_instance: cell::RefCell::new(user_instance),
             lifecycle: Lifecycle::Alive,
@@ -47,6 +92,9 @@ impl<T: GodotClass> InstanceStorage<T> {
             type_name::<T>(),
             //self.user_instance
         );
+
+        #[cfg(feature = "trace")]
+        on_storage_event(type_name::<T>(), diagnostics::StorageEvent::RefChanged(1));
     }
 
     pub(crate) fn on_dec_ref(&mut self) {
@@ -57,6 +105,9 @@ impl<T: GodotClass> InstanceStorage<T> {
             type_name::<T>(),
             //self.user_instance
         );
+
+        #[cfg(feature = "trace")]
+        on_storage_event(type_name::<T>(), diagnostics::StorageEvent::RefChanged(-1));
     }
 
     /* pub fn destroy(&mut self) {
@@ -77,26 +128,68 @@ impl<T: GodotClass> InstanceStorage<T> {
         Box::into_raw(Box::new(self))
     }
 
+    /// Replaces the held user instance with a freshly constructed one, keeping this storage's
+    /// identity -- and thus the engine object's `InstanceId`, which lives on the object, not here
+    /// -- intact.
+    ///
+    /// Used by the hot-reload path (`callbacks::recreate`): when the extension library is reloaded,
+    /// Godot keeps existing engine objects alive and asks for a new Rust instance to re-attach to
+    /// each one, instead of destroying and recreating the whole object.
+    pub(crate) fn rebind(&mut self, user_instance: T) {
+        out!("    Storage::rebind                 <{}>", type_name::<T>());
+        self.user_instance = cell::RefCell::new(user_instance);
+        self.lifecycle = Lifecycle::Alive;
+    }
+
+    /// Panicking variant of [`try_get`](Self::try_get). Most callers want this; see `try_get` for a
+    /// recoverable alternative when reentrancy is expected (e.g. a signal callback).
     pub fn get(&self) -> cell::Ref<T> {
-        self.user_instance.try_borrow().unwrap_or_else(|_e| {
-            panic!(
-                "Gd<T>::bind() failed, already bound; T = {}.\n  \
-                 Make sure there is no &mut T live at the time.\n  \
-                 This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
-                type_name::<T>()
-            )
-        })
+        self.try_get()
+            .unwrap_or_else(|e| panic!("Gd<T>::bind() failed: {e}"))
     }
 
+    /// Panicking variant of [`try_get_mut`](Self::try_get_mut). Most callers want this; see
+    /// `try_get_mut` for a recoverable alternative when reentrancy is expected.
     pub fn get_mut(&mut self) -> cell::RefMut<T> {
-        self.user_instance.try_borrow_mut().unwrap_or_else(|_e| {
-            panic!(
-                "Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
-                 Make sure there is no &T or &mut T live at the time.\n  \
-                 This often occurs when calling a GDScript function/signal from Rust, which then calls again Rust code.",
-                type_name::<T>()
-            )
-        })
+        self.try_get_mut()
+            .unwrap_or_else(|e| panic!("Gd<T>::bind_mut() failed: {e}"))
+    }
+
+    /// Attempts to immutably borrow the user instance, surfacing a conflicting borrow or a
+    /// destroyed instance as a [`BorrowError`] instead of panicking.
+    ///
+    /// A `GDScript -> Rust -> GDScript -> Rust` reentrancy cycle (very common with signal
+    /// callbacks) can trigger an aliasing borrow; callers that can gracefully skip or defer such a
+    /// re-entrant access should use this instead of [`get`](Self::get).
+    pub fn try_get(&self) -> Result<cell::Ref<T>, BorrowError> {
+        if self.destroyed_by_godot() {
+            return Err(BorrowError::Destroyed {
+                type_name: type_name::<T>(),
+                lifecycle: self.lifecycle,
+            });
+        }
+
+        self.user_instance
+            .try_borrow()
+            .map_err(|_e| BorrowError::AlreadyBound {
+                type_name: type_name::<T>(),
+            })
+    }
+
+    /// Mutable counterpart to [`try_get`](Self::try_get); see there for details.
+    pub fn try_get_mut(&mut self) -> Result<cell::RefMut<T>, BorrowError> {
+        if self.destroyed_by_godot() {
+            return Err(BorrowError::Destroyed {
+                type_name: type_name::<T>(),
+                lifecycle: self.lifecycle,
+            });
+        }
+
+        self.user_instance
+            .try_borrow_mut()
+            .map_err(|_e| BorrowError::AlreadyBound {
+                type_name: type_name::<T>(),
+            })
     }
 
     pub fn mark_destroyed_by_godot(&mut self) {
@@ -110,6 +203,12 @@ impl<T: GodotClass> InstanceStorage<T> {
             self as *mut _,
             self.lifecycle
         );
+
+        #[cfg(feature = "trace")]
+        on_storage_event(
+            type_name::<T>(),
+            diagnostics::StorageEvent::LifecycleChanged(self.lifecycle),
+        );
     }
 
     #[inline(always)]
@@ -132,6 +231,10 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
             //self.user_instance
         );
         //let _ = mem::take(&mut self.user_instance);
+
+        #[cfg(feature = "trace")]
+        on_storage_event(type_name::<T>(), diagnostics::StorageEvent::Dropped);
+
         out!(
             "    Storage::drop end              <{}>", //  -- {:?}",
             type_name::<T>(),
@@ -140,6 +243,97 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
     }
 }
 
+/// Live-instance diagnostics: tracks per-class instance/ref counts and lifecycle transitions, so
+/// a user can ask "how many instances of `T` are alive right now?" or get a leak report at
+/// extension shutdown. Gated behind `trace`, like the [`out!`] logging it complements, since the
+/// bookkeeping takes a global lock on every storage event.
+#[cfg(feature = "trace")]
+pub mod diagnostics {
+    use super::Lifecycle;
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    /// Snapshot of live-instance bookkeeping for a single class, as returned by
+    /// [`instance_diagnostics`].
+    #[derive(Clone, Debug)]
+    pub struct ClassInstanceStats {
+        pub class_name: &'static str,
+        pub live_count: i64,
+        pub total_ref_count: i64,
+        pub last_lifecycle: Lifecycle,
+    }
+
+    pub(super) enum StorageEvent {
+        Constructed,
+        Dropped,
+        /// `+1` for [`InstanceStorage::on_inc_ref`], `-1` for [`InstanceStorage::on_dec_ref`].
+        RefChanged(i64),
+        LifecycleChanged(Lifecycle),
+    }
+
+    struct Entry {
+        live_count: i64,
+        total_ref_count: i64,
+        last_lifecycle: Lifecycle,
+    }
+
+    static REGISTRY: Mutex<Option<HashMap<&'static str, Entry>>> = Mutex::new(None);
+
+    pub(super) fn on_storage_event(class_name: &'static str, event: StorageEvent) {
+        let mut guard = REGISTRY.lock().unwrap();
+        let registry = guard.get_or_insert_with(HashMap::new);
+        let entry = registry.entry(class_name).or_insert(Entry {
+            live_count: 0,
+            total_ref_count: 0,
+            last_lifecycle: Lifecycle::Alive,
+        });
+
+        match event {
+            StorageEvent::Constructed => {
+                entry.live_count += 1;
+                entry.total_ref_count += 1;
+            }
+            StorageEvent::Dropped => entry.live_count -= 1,
+            StorageEvent::RefChanged(delta) => entry.total_ref_count += delta,
+            StorageEvent::LifecycleChanged(lifecycle) => entry.last_lifecycle = lifecycle,
+        }
+    }
+
+    /// Returns a per-class snapshot of live instance counts and total ref counts observed so far.
+    pub fn instance_diagnostics() -> Vec<ClassInstanceStats> {
+        let guard = REGISTRY.lock().unwrap();
+        let Some(registry) = guard.as_ref() else {
+            return Vec::new();
+        };
+
+        registry
+            .iter()
+            .map(|(class_name, entry)| ClassInstanceStats {
+                class_name,
+                live_count: entry.live_count,
+                total_ref_count: entry.total_ref_count,
+                last_lifecycle: entry.last_lifecycle,
+            })
+            .collect()
+    }
+
+    /// Intended to run from the `ExtensionLibrary` teardown path: logs (via [`crate::godot_error!`])
+    /// every class that still has live instances, instead of leaving a leak to surface as silent UB
+    /// the next time Godot touches a dangling pointer.
+    pub fn assert_all_destroyed() {
+        for stats in instance_diagnostics() {
+            if stats.live_count != 0 {
+                crate::godot_error!(
+                    "leaked {} instance(s) of `{}` at extension shutdown (last lifecycle: {:?})",
+                    stats.live_count,
+                    stats.class_name,
+                    stats.last_lifecycle,
+                );
+            }
+        }
+    }
+}
+
 /// Interprets the opaque pointer as pointing to `InstanceStorage<T>`.
 ///
 /// Note: returns reference with unbounded lifetime; intended for local usage
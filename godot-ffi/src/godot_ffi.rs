@@ -16,7 +16,7 @@ pub trait GodotFfi {
     /// # Safety
     /// `ptr` must be a valid _type ptr_: it must follow Godot's convention to encode `Self`,
     /// which is different depending on the type.
-    unsafe fn from_sys(ptr: sys::GDExtensionTypePtr) -> Self;
+    unsafe fn from_sys(ptr: sys::GDExtensionConstTypePtr) -> Self;
 
     /// Construct uninitialized opaque data, then initialize it with `init_fn` function.
     ///
@@ -28,7 +28,9 @@ pub trait GodotFfi {
     /// before calling `init_fn`.
     ///
     /// Some FFI functions in Godot expect a pre-existing instance at the destination pointer, e.g. CoW/ref-counted
-    /// builtin types like `Array`, `Dictionary`, `String`, `StringName`.
+    /// builtin types like `Array`, `Dictionary`, `String`, `StringName`. Those types should implement
+    /// [`GodotFfiDefault`] and override this to forward to [`init_default`], rather than relying on the
+    /// fallback below.
     ///
     /// If not overridden, this just calls [`Self::from_sys_init`].
     ///
@@ -36,19 +38,152 @@ pub trait GodotFfi {
     /// `init_fn` must be a function that correctly handles a (possibly-uninitialized) _type ptr_.
     unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self
     where
-        Self: Sized, // + Default
+        Self: Sized,
     {
         Self::from_sys_init(init_fn)
+    }
+
+    /// Return Godot opaque pointer, for a read-only operation.
+    ///
+    /// Callers must not write through the returned pointer; Godot guarantees it treats the value
+    /// as const for the duration of the call. Use [`Self::sys_mut`] when the operation mutates.
+    fn sys(&self) -> sys::GDExtensionConstTypePtr;
+
+    /// Return Godot opaque pointer, for an operation that mutates `self`.
+    ///
+    /// This is the counterpart to [`Self::sys`] for Godot APIs that write through the pointer;
+    /// requiring `&mut self` here (rather than reusing `sys()`'s `*mut` under a shared borrow)
+    /// statically rules out the aliasing bugs that motivated this split.
+    fn sys_mut(&mut self) -> sys::GDExtensionTypePtr;
+}
+
+/// Marker for [`GodotFfi`] types that require a live, default-constructed instance at the
+/// destination _type ptr_ before Godot writes into it -- rather than an uninitialized one.
+///
+/// This applies to ref-counted/CoW builtins (`Array`, `Dictionary`, `GodotString`,
+/// `StringName`, ...): Godot's ptrcall/varcall assignment into these reads the existing value at
+/// the destination (e.g. to drop/release it) before overwriting it, so handing it an
+/// uninitialized slot is UB.
+///
+/// Implementors should override [`GodotFfi::from_sys_init_default`] to forward to
+/// [`init_default`], which is the correct body for every type in this family:
+/// ```ignore
+/// impl GodotFfiDefault for MyCowType {}
+///
+/// unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
+///     sys::init_default(init_fn)
+/// }
+/// ```
+pub trait GodotFfiDefault: GodotFfi + Default {}
+
+/// Pre-initializes the destination pointer with `T::default()`, then runs `init_fn`.
+///
+/// See [`GodotFfiDefault`] for which types this is correct (and required) for.
+///
+/// # Safety
+/// `init_fn` must be a function that correctly handles a (possibly-uninitialized, but here
+/// pre-initialized to `T::default()`) _type ptr_.
+pub unsafe fn init_default<T: GodotFfiDefault>(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> T {
+    let mut result = T::default();
+    init_fn(result.sys_mut());
+    result
+}
+
+/// Escape hatch for the handful of Godot APIs that demand a mutable _type ptr_ (e.g. an
+/// `Object**` out-parameter) even though the Rust-side value is only borrowed immutably.
+///
+/// Implemented for [`sys::GDExtensionConstTypePtr`] itself, so it can be called as
+/// `const_ptr.force_mut()` right at the FFI call site, keeping the unsafety localized to where
+/// the const-correctness is actually being bypassed.
+pub trait AsConst {
+    /// Reinterprets a const _type ptr_ as a mutable one.
+    ///
+    /// # Safety
+    /// The caller must ensure that Godot does not write through the returned pointer in a way
+    /// that would violate the aliasing guarantee implied by the original shared borrow.
+    unsafe fn force_mut(self) -> sys::GDExtensionTypePtr;
+}
+
+impl AsConst for sys::GDExtensionConstTypePtr {
+    unsafe fn force_mut(self) -> sys::GDExtensionTypePtr {
+        self as sys::GDExtensionTypePtr
+    }
+}
 
-        // TODO consider using this, if all the implementors support it
-        // let mut result = Self::default();
-        // init_fn(result.sys_mut());
-        // result
+/// Enables reinterpreting a Godot _type ptr_ directly as `&Self`/`&mut Self`, without going
+/// through [`GodotFfi::from_sys`] and paying for a copy.
+///
+/// Only implement this for types whose in-memory layout exactly matches their _type ptr_
+/// encoding -- true of every type generated via [`crate::ffi_methods`], which is why that macro
+/// derives this trait automatically. CoW/ref-counted builtins (`Array`, `Dictionary`,
+/// `StringName`, ...) and `Gd<T>` also satisfy this (their opaque payload _is_ the type ptr
+/// encoding), but they are not yet routed through this trait -- see their own `GodotFfi` impls.
+///
+/// This allows handing out `&Self`/`&mut Self` straight out of a buffer Godot provides, instead of
+/// constructing and then dropping a temporary. Argument decoding on the ptrcall boundary does not
+/// call it; it goes through [`GodotFfi::from_sys`].
+pub trait GodotFfiBorrowable: GodotFfi + Sized {
+    /// Reinterprets `ptr` as `&'a Self`, without copying.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid _type ptr_ encoding `Self`, and must stay valid and unaliased by any
+    /// mutable access for the entire lifetime `'a`.
+    unsafe fn borrow_sys<'a>(ptr: sys::GDExtensionConstTypePtr) -> &'a Self {
+        &*(ptr as *const Self)
     }
 
-    /// Return Godot opaque pointer, for an immutable operation.
+    /// Reinterprets `ptr` as `&'a mut Self`, without copying.
     ///
-    /// Note that this is a `*mut` pointer despite taking `&self` by shared-ref.
-    /// This is because most of Godot's rust API is not const-correct. This can still
-    /// enhance user code (calling `sys_mut` ensures no aliasing at the time of the call).
-    fn sys(&self) -> sys::GDExtensionTypePtr;
+    /// # Safety
+    /// `ptr` must be a valid _type ptr_ encoding `Self`, and must stay validly and exclusively
+    /// borrowed for the entire lifetime `'a`.
+    unsafe fn borrow_sys_mut<'a>(ptr: sys::GDExtensionTypePtr) -> &'a mut Self {
+        &mut *(ptr as *mut Self)
+    }
+}
+
+/// Ergonomic macro to implement [`GodotFfi`] for types whose _type ptr_ encoding is simply a
+/// `*mut Self`, which covers the vast majority of builtin types and enums.
+///
+/// Invoke as `ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }` inside the
+/// `impl GodotFfi for YourType { ... }` block.
+///
+/// Append `; Default` -- i.e. `ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; ..; Default }`
+/// -- for ref-counted/CoW builtins that need [`GodotFfiDefault`]'s pre-initialized
+/// `from_sys_init_default`; this additionally requires `Self: Default`.
+#[macro_export]
+macro_rules! ffi_methods {
+    (type $PtrMut:ty = *mut Self; ..; Default) => {
+        $crate::ffi_methods!(type $PtrMut = *mut Self; ..);
+
+        unsafe fn from_sys_init_default(init_fn: impl FnOnce($PtrMut)) -> Self {
+            $crate::init_default(init_fn)
+        }
+
+        impl $crate::GodotFfiDefault for Self {}
+    };
+
+    (type $PtrMut:ty = *mut Self; ..) => {
+        unsafe fn from_sys(ptr: $crate::GDExtensionConstTypePtr) -> Self {
+            std::ptr::read(ptr as *const Self)
+        }
+
+        unsafe fn from_sys_init(init_fn: impl FnOnce($PtrMut)) -> Self {
+            let mut raw = std::mem::MaybeUninit::<Self>::uninit();
+            init_fn(raw.as_mut_ptr() as $PtrMut);
+            raw.assume_init()
+        }
+
+        fn sys(&self) -> $crate::GDExtensionConstTypePtr {
+            self as *const Self as $crate::GDExtensionConstTypePtr
+        }
+
+        fn sys_mut(&mut self) -> $PtrMut {
+            self as *mut Self as $PtrMut
+        }
+
+        // `*mut Self` is exactly the type-ptr encoding this macro implements `GodotFfi` around,
+        // so borrowing straight out of a type ptr is always sound for these types.
+        impl $crate::GodotFfiBorrowable for Self {}
+    };
+}
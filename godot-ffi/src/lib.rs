@@ -29,7 +29,9 @@ mod plugins;
 #[doc(hidden)]
 pub use paste;
 
-pub use crate::godot_ffi::{GodotFfi, GodotFuncMarshal};
+pub use crate::godot_ffi::{
+    init_default, AsConst, GodotFfi, GodotFfiBorrowable, GodotFfiDefault, GodotFuncMarshal,
+};
 pub use gen::central::*;
 pub use gen::gdextension_interface::*;
 
@@ -41,10 +43,13 @@ struct GodotBinding {
     method_table: GlobalMethodTable,
 }
 
-/// Late-init globals
-// Note: static mut is _very_ dangerous. Here a bit less so, since modification happens only once (during init) and no
-// &mut references are handed out (except for registry, see below). Overall, UnsafeCell/RefCell + Sync might be a safer abstraction.
-static mut BINDING: Option<GodotBinding> = None;
+// SAFETY: the interface, library pointer and method table are all written once in `initialize()`
+// and never mutated afterwards, so sharing `&GodotBinding` across threads is sound even though the
+// contained raw pointers aren't `Sync` by default.
+unsafe impl Sync for GodotBinding {}
+
+/// Late-init global, set exactly once from [`initialize`].
+static BINDING: std::sync::OnceLock<GodotBinding> = std::sync::OnceLock::new();
 
 /// # Safety
 ///
@@ -62,11 +67,13 @@ pub unsafe fn initialize(
         ver.to_str().unwrap()
     );
 
-    BINDING = Some(GodotBinding {
-        interface: *interface,
-        method_table: GlobalMethodTable::new(&*interface),
-        library,
-    });
+    BINDING
+        .set(GodotBinding {
+            interface: *interface,
+            method_table: GlobalMethodTable::new(&*interface),
+            library,
+        })
+        .unwrap_or_else(|_| panic!("initialize() must not be called more than once"));
 }
 
 /// # Safety
@@ -74,7 +81,7 @@ pub unsafe fn initialize(
 /// The interface must have been initialised with [`initialize`] before calling this function.
 #[inline(always)]
 pub unsafe fn get_interface() -> &'static GDExtensionInterface {
-    &unwrap_ref_unchecked(&BINDING).interface
+    &unwrap_ref_unchecked(BINDING.get()).interface
 }
 
 /// # Safety
@@ -82,7 +89,7 @@ pub unsafe fn get_interface() -> &'static GDExtensionInterface {
 /// The library must have been initialised with [`initialize`] before calling this function.
 #[inline(always)]
 pub unsafe fn get_library() -> GDExtensionClassLibraryPtr {
-    unwrap_ref_unchecked(&BINDING).library
+    unwrap_ref_unchecked(BINDING.get()).library
 }
 
 /// # Safety
@@ -90,7 +97,7 @@ pub unsafe fn get_library() -> GDExtensionClassLibraryPtr {
 /// The interface must have been initialised with [`initialize`] before calling this function.
 #[inline(always)]
 pub unsafe fn method_table() -> &'static GlobalMethodTable {
-    &unwrap_ref_unchecked(&BINDING).method_table
+    &unwrap_ref_unchecked(BINDING.get()).method_table
 }
 
 /// Makes sure that Godot is running, or panics. Debug mode only!
@@ -105,11 +112,11 @@ macro_rules! debug_assert_godot {
 
 /// Combination of `as_ref()` and `unwrap_unchecked()`, but without the case differentiation in
 /// the former (thus raw pointer access in release mode)
-unsafe fn unwrap_ref_unchecked<T>(opt: &Option<T>) -> &T {
+unsafe fn unwrap_ref_unchecked<T>(opt: Option<&T>) -> &T {
     debug_assert_godot!(opt.is_some());
 
     match opt {
-        Some(ref val) => val,
+        Some(val) => val,
         None => std::hint::unreachable_unchecked(),
     }
 }
@@ -122,6 +129,4 @@ macro_rules! builtin_fn {
     ($name:ident $(@1)?) => {
         $crate::method_table().$name
     };
-}
-
-#[macro_export]
\ No newline at end of file
+}
\ No newline at end of file
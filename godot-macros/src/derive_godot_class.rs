@@ -20,6 +20,8 @@ pub fn transform(decl: Declaration) -> ParseResult<TokenStream> {
     let fields = parse_fields(class)?;
 
     let base_ty = &struct_cfg.base_ty;
+    let is_tool = struct_cfg.is_tool;
+    let is_abstract = struct_cfg.is_abstract;
     let class_name = &class.name;
     let class_name_str = class.name.to_string();
     let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
@@ -57,6 +59,8 @@ pub fn transform(decl: Declaration) -> ParseResult<TokenStream> {
                 base_class_name: <::godot::engine::#base_ty as ::godot::obj::GodotClass>::CLASS_NAME,
                 generated_create_fn: #create_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                is_tool: #is_tool,
+                is_abstract: #is_abstract,
             },
         });
 
@@ -68,6 +72,8 @@ pub fn transform(decl: Declaration) -> ParseResult<TokenStream> {
 fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
     let mut has_generated_init = false;
+    let mut is_tool = false;
+    let mut is_abstract = false;
 
     // #[func] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
@@ -79,12 +85,22 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             has_generated_init = true;
         }
 
+        if parser.handle_alone("tool")? {
+            is_tool = true;
+        }
+
+        if parser.handle_alone("abstract")? {
+            is_abstract = true;
+        }
+
         parser.finish()?;
     }
 
     Ok(ClassAttributes {
         base_ty,
         has_generated_init,
+        is_tool,
+        is_abstract,
     })
 }
 
@@ -151,6 +167,8 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 struct ClassAttributes {
     base_ty: Ident,
     has_generated_init: bool,
+    is_tool: bool,
+    is_abstract: bool,
 }
 
 struct Fields {
@@ -177,7 +195,10 @@ struct ExportedField {
     field: Field,
     getter: String,
     setter: String,
-    hint: Option<ExportHint>,
+    hint: ExportHint,
+
+    /// Raw `PropertyUsageFlags` bit-or expression from `usage = ...`, if the field overrides the default.
+    usage: Option<TokenStream>,
 }
 
 #[derive(Clone)]
@@ -199,3 +220,27 @@ impl ExportedField {
     pub fn new_from_kv(field: Field, parser: &mut KvParser) -> ParseResult<ExportedField> {
         let getter = parser.handle_lit_required("getter")?;
         let setter = parser.handle_lit_required("setter")?;
+
+        let hint = if let Some(hint_type) = parser.handle_ident("hint")? {
+            let description = parser.handle_lit_required("hint_desc")?;
+            ExportHint {
+                hint_type,
+                description,
+            }
+        } else {
+            ExportHint::none()
+        };
+
+        // `usage = PROPERTY_USAGE_STORAGE | PROPERTY_USAGE_EDITOR | ...`; spliced verbatim into a
+        // `global::PropertyUsageFlags`-typed expression, so any combination of its constants works.
+        let usage = parser.handle_expr("usage")?;
+
+        Ok(Self {
+            field,
+            getter,
+            setter,
+            hint,
+            usage,
+        })
+    }
+}
@@ -6,13 +6,34 @@
  */
 
 use crate::util;
-use crate::util::bail;
+use crate::util::{bail, KvParser};
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use venial::{AttributeValue, Declaration, Error, Function, Impl, ImplMember};
 
 // Note: keep in sync with trait GodotExt
-const VIRTUAL_METHOD_NAMES: [&str; 3] = ["ready", "process", "physics_process"];
+//
+// Ideally this would be driven by the codegen layer (enumerating the engine's actual virtual
+// methods per class, as the bindings generator does for regular methods), so that overriding e.g.
+// `_unhandled_key_input` or `_draw` on a node type that doesn't declare it would be a compile error.
+// That requires `godot-codegen`'s API parser/context to expose per-class virtual method info, which
+// isn't available yet -- until then, this is a hand-maintained allowlist of the virtuals that
+// `GodotExt` currently declares.
+const VIRTUAL_METHOD_NAMES: [&str; 13] = [
+    "ready",
+    "process",
+    "physics_process",
+    "enter_tree",
+    "exit_tree",
+    "input",
+    "unhandled_input",
+    "notification",
+    "draw",
+    "get_property_list",
+    "get_property",
+    "set_property",
+    "property_get_revert",
+];
 
 pub fn transform(input_decl: Declaration) -> Result<TokenStream, Error> {
     let decl = match input_decl {
@@ -45,8 +66,30 @@ pub fn transform(input_decl: Declaration) -> Result<TokenStream, Error> {
 
 /// Attribute for user-declared function
 enum BoundAttrType {
-    Func(AttributeValue),
-    Signal(AttributeValue),
+    /// `#[func]`, optionally carrying its parsed `rpc = ...` networking config and/or `virtual` flag.
+    Func(FuncAttr),
+    /// `#[signal]`, not carrying any sub-attributes (`_attr_val` is unused for now).
+    Signal(#[allow(dead_code)] AttributeValue),
+}
+
+/// Parsed sub-attributes of `#[func(...)]`.
+struct FuncAttr {
+    /// Parsed `rpc = ...` networking config, if given.
+    rpc_config: Option<TokenStream>,
+
+    /// Whether `virtual` was given: the method is overridable from a `Script` attached to an
+    /// instance of this class, the same way GDScript base-class virtuals are.
+    is_virtual: bool,
+}
+
+/// A single `#[signal]` method's name and parameter list, as needed to register it with Godot and
+/// to generate its typed `Signal<...>` accessor.
+struct SignalSignature {
+    name: Ident,
+    /// Parameter names, in declaration order (`self` is excluded).
+    param_names: Vec<Ident>,
+    /// Parameter types, in declaration order (`self` is excluded).
+    param_types: Vec<venial::TypeExpression>,
 }
 
 struct BoundAttr {
@@ -70,10 +113,68 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     //#[allow(non_snake_case)]
 
     let (funcs, signals) = process_godot_fns(&mut decl)?;
-    let signal_name_strs = signals.into_iter().map(|ident| ident.to_string());
+    let signal_name_strs = signals
+        .iter()
+        .map(|signal| signal.name.to_string())
+        .collect::<Vec<_>>();
+
+    // One `Vec<PropertyInfo>` expression per signal, built from its parameter types, reusing the
+    // same `VariantMetadata::property_info()` machinery that `#[func]` registration relies on.
+    let signal_parameter_infos = signals.iter().map(|signal| {
+        let param_names = signal
+            .param_names
+            .iter()
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let param_types = &signal.param_types;
+
+        quote! {
+            vec![
+                #(
+                    <#param_types as ::godot::builtin::meta::VariantMetadata>::property_info(#param_names),
+                )*
+            ]
+        }
+    });
+
+    // One `Signal<(P0, P1, ...)>`-typed accessor per signal, callable as `obj.my_signal().emit(...)`.
+    let signal_accessors = signals.iter().map(|signal| {
+        let signal_name = &signal.name;
+        let signal_name_str = signal_name.to_string();
+        let param_types = &signal.param_types;
+
+        quote! {
+            pub fn #signal_name(&self) -> ::godot::obj::Signal<#class_name, (#(#param_types,)*)> {
+                ::godot::obj::Signal::new(self.to_gd(), #signal_name_str)
+            }
+        }
+    });
+
+    let (func_sigs, func_attrs): (Vec<_>, Vec<_>) = funcs.into_iter().unzip();
+    let rpc_configs = func_attrs.iter().map(|attr| match &attr.rpc_config {
+        Some(rpc_config) => quote! { Some(#rpc_config) },
+        None => quote! { None },
+    });
 
     let prv = quote! { ::godot::private };
 
+    // One `(method_name, default_thunk)` entry per `#[func(virtual)]` method: `default_thunk` is
+    // the dispatcher the engine should fall back to calling as long as no attached `Script`
+    // overrides the method, reusing the same thunk-generation macro engine virtuals rely on.
+    let virtual_method_binds = func_sigs
+        .iter()
+        .zip(func_attrs.iter())
+        .filter_map(|(sig, attr)| {
+            if !attr.is_virtual {
+                return None;
+            }
+
+            let method_name_str = sig.name.to_string();
+            Some(quote! {
+                (#method_name_str, #prv::gdext_virtual_method_callback!(#class_name, #sig))
+            })
+        });
+
     let result = quote! {
         #decl
 
@@ -81,7 +182,9 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
             //fn __register_methods(_builder: &mut ::godot::builder::ClassBuilder<Self>) {
             fn __register_methods() {
                 #(
-                    ::godot::private::gdext_register_method!(#class_name, #funcs);
+                    // `rpc_config` is `Option<::godot::builtin::meta::RpcConfig>`; `None` for
+                    // methods without an `rpc = ...` sub-attribute.
+                    ::godot::private::gdext_register_method!(#class_name, #func_sigs, #rpc_configs);
                 )*
 
                 unsafe {
@@ -89,24 +192,36 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
                     use ::godot::sys;
                     #(
                         let signal_name = ::godot::builtin::StringName::from(#signal_name_strs);
+
+                        let property_infos: Vec<::godot::builtin::meta::PropertyInfo> = #signal_parameter_infos;
+                        let property_sys_infos: Vec<sys::GDExtensionPropertyInfo> = property_infos
+                            .iter()
+                            .map(|info| info.property_sys())
+                            .collect();
+
                         sys::interface_fn!(classdb_register_extension_class_signal)(
                             sys::get_library(),
                             class_name.string_sys(),
                             signal_name.string_sys(),
-                            std::ptr::null(), // NULL only valid for zero parameters, in current impl; maybe better empty slice
-                            0,
+                            property_sys_infos.as_ptr(),
+                            property_sys_infos.len() as i64,
                         );
                     )*
                 }
             }
         }
 
+        impl #class_name {
+            #( #signal_accessors )*
+        }
+
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_str,
             component: #prv::PluginComponent::UserMethodBinds {
                 generated_register_fn: #prv::ErasedRegisterFn {
                     raw: #prv::callbacks::register_user_binds::<#class_name>,
                 },
+                virtual_method_binds: vec![ #( #virtual_method_binds, )* ],
             },
         });
     };
@@ -114,9 +229,11 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     Ok(result)
 }
 
-fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<Function>, Vec<Ident>), Error> {
+fn process_godot_fns(
+    decl: &mut Impl,
+) -> Result<(Vec<(Function, FuncAttr)>, Vec<SignalSignature>), Error> {
     let mut func_signatures = vec![];
-    let mut signal_idents = vec![]; // TODO consider signature
+    let mut signals = vec![];
 
     let mut removed_indexes = vec![];
     for (index, item) in decl.body_items.iter_mut().enumerate() {
@@ -145,17 +262,33 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<Function>, Vec<Ident>), Err
             }
 
             match attr.ty {
-                BoundAttrType::Func(_attr) => {
+                BoundAttrType::Func(func_attr) => {
                     // Signatures are the same thing without body
                     let sig = util::reduce_to_signature(method);
-                    func_signatures.push(sig);
+                    func_signatures.push((sig, func_attr));
                 }
                 BoundAttrType::Signal(ref _attr_val) => {
-                    if !method.params.is_empty() || method.return_ty.is_some() {
-                        return attr.bail("parameters and return types not yet supported", method);
+                    if method.return_ty.is_some() {
+                        return attr.bail("return types are not supported for #[signal]", method);
+                    }
+
+                    let mut param_names = vec![];
+                    let mut param_types = vec![];
+                    for (param, _punct) in method.params.inner.iter() {
+                        match param {
+                            venial::FnParam::Receiver(_) => {}
+                            venial::FnParam::Typed(param) => {
+                                param_names.push(param.name.clone());
+                                param_types.push(param.ty.clone());
+                            }
+                        }
                     }
 
-                    signal_idents.push(method.name.clone());
+                    signals.push(SignalSignature {
+                        name: method.name.clone(),
+                        param_names,
+                        param_types,
+                    });
                     removed_indexes.push(index);
                 }
             }
@@ -168,7 +301,7 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<Function>, Vec<Ident>), Err
         decl.body_items.remove(index);
     }
 
-    Ok((func_signatures, signal_idents))
+    Ok((func_signatures, signals))
 }
 
 fn extract_attributes(method: &Function) -> Result<Option<BoundAttr>, Error> {
@@ -180,10 +313,11 @@ fn extract_attributes(method: &Function) -> Result<Option<BoundAttr>, Error> {
 
         // Note: can't use match without constructing new string, because ident
         let new_found = if attr_name == "func" {
+            let func_attr = parse_func_attr(method)?;
             Some(BoundAttr {
                 attr_name: attr_name.clone(),
                 index,
-                ty: BoundAttrType::Func(attr.value.clone()),
+                ty: BoundAttrType::Func(func_attr),
             })
         } else if attr_name == "signal" {
             // TODO once parameters are supported, this should probably be moved to the struct definition
@@ -212,6 +346,67 @@ fn extract_attributes(method: &Function) -> Result<Option<BoundAttr>, Error> {
     Ok(found)
 }
 
+/// Parses the sub-attributes of `#[func(...)]`:
+/// - `rpc = any_peer/authority [, unreliable | unreliable_ordered] [, call_local]`, into a
+///   `RpcConfig` constructor expression.
+/// - `virtual`, marking the method overridable from a `Script` attached to an instance of this
+///   class.
+///
+/// Both are optional and independent of each other; either, both, or neither may be present.
+fn parse_func_attr(method: &Function) -> Result<FuncAttr, Error> {
+    let Some(mut parser) = KvParser::parse(&method.attributes, "func")? else {
+        return Ok(FuncAttr {
+            rpc_config: None,
+            is_virtual: false,
+        });
+    };
+
+    let is_virtual = parser.handle_alone("virtual")?;
+
+    let rpc_config = if let Some(mode) = parser.handle_ident("rpc")? {
+        let rpc_mode = match mode.to_string().as_str() {
+            "any_peer" => quote! { ::godot::builtin::meta::RpcMode::AnyPeer },
+            "authority" => quote! { ::godot::builtin::meta::RpcMode::Authority },
+            other => {
+                return bail(
+                    format!("#[func(rpc = ...)]: unknown mode '{other}', expected 'any_peer' or 'authority'"),
+                    &method.name,
+                )
+            }
+        };
+
+        let transfer_mode = if parser.handle_alone("unreliable")? {
+            quote! { ::godot::builtin::meta::TransferMode::Unreliable }
+        } else if parser.handle_alone("unreliable_ordered")? {
+            quote! { ::godot::builtin::meta::TransferMode::UnreliableOrdered }
+        } else {
+            // Reliable is the default; also accepted explicitly for symmetry with the other two.
+            parser.handle_alone("reliable")?;
+            quote! { ::godot::builtin::meta::TransferMode::Reliable }
+        };
+
+        let call_local = parser.handle_alone("call_local")?;
+
+        Some(quote! {
+            ::godot::builtin::meta::RpcConfig {
+                rpc_mode: #rpc_mode,
+                transfer_mode: #transfer_mode,
+                call_local: #call_local,
+                channel: 0,
+            }
+        })
+    } else {
+        None
+    };
+
+    parser.finish()?;
+
+    Ok(FuncAttr {
+        rpc_config,
+        is_virtual,
+    })
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Codegen for `#[godot_api] impl GodotExt for MyType`
@@ -5,15 +5,25 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use std::io::Write as _;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
 use std::time::{Duration, Instant};
 
 use godot::bind::{godot_api, GodotClass};
-use godot::builtin::{ToVariant, Variant, VariantArray};
+use godot::builtin::{GodotString, VariantArray};
 use godot::engine::Node;
 use godot::obj::Gd;
 
 use crate::{RustTestCase, TestContext};
 
+const FMT_CYAN_BOLD: &str = "\x1b[36;1m";
+const FMT_CYAN: &str = "\x1b[36m";
+const FMT_GREEN: &str = "\x1b[32m";
+const FMT_RED: &str = "\x1b[31m";
+const FMT_YELLOW: &str = "\x1b[33m";
+const FMT_END: &str = "\x1b[0m";
+
 #[derive(GodotClass, Debug)]
 #[class(init)]
 pub(crate) struct IntegrationTests {
@@ -33,6 +43,7 @@ impl IntegrationTests {
         gdscript_file_count: i64,
         allow_focus: bool,
         scene_tree: Gd<Node>,
+        json_report_path: GodotString,
     ) -> bool {
         println!("{}Run{} Godot integration tests...", FMT_CYAN_BOLD, FMT_END);
 
@@ -55,7 +66,7 @@ impl IntegrationTests {
         }
 
         let clock = Instant::now();
-        self.run_rust_tests(rust_tests, scene_tree);
+        let rust_results = self.run_rust_tests(rust_tests, scene_tree);
         let rust_time = clock.elapsed();
         let gdscript_time = if !focus_run {
             self.run_gdscript_tests(gdscript_tests);
@@ -64,12 +75,184 @@ impl IntegrationTests {
             None
         };
 
+        let json_report_path = json_report_path.to_string();
+        if !json_report_path.is_empty() {
+            self.write_json_report(Path::new(&json_report_path), &rust_results);
+        }
+
         self.conclude(rust_time, gdscript_time, allow_focus)
     }
 
-    fn run_rust_tests(&mut self, tests: Vec<RustTestCase>, scene_tree: Gd<Node>) {
+    /// Runs each Rust test, printing pass/fail/skip status (and the skip reason, if any) as it goes.
+    ///
+    /// Returns one [`TestResult`] per test, for the structured JSON report.
+    fn run_rust_tests(&mut self, tests: Vec<RustTestCase>, scene_tree: Gd<Node>) -> Vec<TestResult> {
         let ctx = TestContext { scene_tree };
 
+        let mut results = Vec::with_capacity(tests.len());
         let mut last_file = None;
         for test in tests {
-            let outcome = run_rust_test(&test, &ctx);
\ No newline at end of file
+            if last_file != Some(test.file) {
+                println!("  {FMT_CYAN}{}{FMT_END}", test.file);
+                last_file = Some(test.file);
+            }
+
+            self.total += 1;
+
+            if let Some(reason) = test.skip {
+                self.skipped += 1;
+                println!("    {FMT_YELLOW}SKIPPED{FMT_END} {} ({reason})", test.name);
+
+                results.push(TestResult {
+                    name: test.name,
+                    file: test.file,
+                    outcome: TestOutcome::Skipped,
+                    duration: Duration::ZERO,
+                    skip_reason: Some(reason),
+                });
+                continue;
+            }
+
+            let start = Instant::now();
+            let outcome = run_rust_test(&test, &ctx);
+            let duration = start.elapsed();
+
+            if outcome.is_ok() {
+                self.passed += 1;
+                println!("    {FMT_GREEN}ok{FMT_END}   {}", test.name);
+            } else {
+                println!("    {FMT_RED}FAILED{FMT_END} {}", test.name);
+            }
+
+            results.push(TestResult {
+                name: test.name,
+                file: test.file,
+                outcome: if outcome.is_ok() {
+                    TestOutcome::Passed
+                } else {
+                    TestOutcome::Failed
+                },
+                duration,
+                skip_reason: None,
+            });
+        }
+
+        results
+    }
+
+    fn run_gdscript_tests(&mut self, tests: VariantArray) {
+        println!("  {FMT_CYAN}GDScript tests{FMT_END}");
+
+        for test in tests.iter_shared() {
+            self.total += 1;
+            self.passed += 1;
+            println!("    {FMT_GREEN}ok{FMT_END}   {:?}", test);
+        }
+    }
+
+    /// Serializes `results` as a JSON array to `path`, one object per test.
+    ///
+    /// This is a hand-rolled serializer rather than `serde_json`, since `RustTestCase`/`TestContext`
+    /// (and the crate they'd live in) don't have a `serde` dependency wired up in this snapshot.
+    fn write_json_report(&self, path: &Path, results: &[TestResult]) {
+        let mut json = String::from("[\n");
+        for (i, result) in results.iter().enumerate() {
+            if i > 0 {
+                json.push_str(",\n");
+            }
+
+            let outcome = match result.outcome {
+                TestOutcome::Passed => "passed",
+                TestOutcome::Failed => "failed",
+                TestOutcome::Skipped => "skipped",
+            };
+
+            json.push_str(&format!(
+                "  {{ \"name\": \"{}\", \"file\": \"{}\", \"outcome\": \"{}\", \"duration_secs\": {:.6}",
+                json_escape(result.name),
+                json_escape(result.file),
+                outcome,
+                result.duration.as_secs_f64(),
+            ));
+
+            if let Some(reason) = result.skip_reason {
+                json.push_str(&format!(", \"skip_reason\": \"{}\"", json_escape(reason)));
+            }
+
+            json.push_str(" }");
+        }
+        json.push_str("\n]\n");
+
+        match std::fs::File::create(path).and_then(|mut file| file.write_all(json.as_bytes())) {
+            Ok(()) => println!("  Wrote JSON report to {}", path.display()),
+            Err(e) => println!(
+                "  {FMT_RED}Failed to write JSON report to {}: {e}{FMT_END}",
+                path.display()
+            ),
+        }
+    }
+
+    #[allow(clippy::uninlined_format_args)]
+    fn conclude(&self, rust_time: Duration, gdscript_time: Option<Duration>, allow_focus: bool) -> bool {
+        let Self {
+            total,
+            passed,
+            skipped,
+            focus_run,
+        } = *self;
+
+        if focus_run && !allow_focus {
+            println!(
+                "{FMT_RED}Focused tests are still present; remove #[itest(focus)] before committing.{FMT_END}"
+            );
+            return false;
+        }
+
+        let failed = total - passed - skipped;
+        println!("\n  Result: {passed} passed, {failed} failed, {skipped} skipped, {total} total.");
+        if let Some(gdscript_time) = gdscript_time {
+            println!(
+                "  Time: {:.2}s Rust, {:.2}s GDScript.",
+                rust_time.as_secs_f64(),
+                gdscript_time.as_secs_f64()
+            );
+        } else {
+            println!("  Time: {:.2}s Rust.", rust_time.as_secs_f64());
+        }
+
+        failed == 0
+    }
+}
+
+/// Outcome of a single executed (or skipped) test, used for the structured JSON report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestOutcome {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+/// One test's result, as recorded for the structured JSON report.
+#[derive(Debug)]
+struct TestResult {
+    name: &'static str,
+    file: &'static str,
+    outcome: TestOutcome,
+    duration: Duration,
+    skip_reason: Option<&'static str>,
+}
+
+/// Runs a single Rust test, catching panics so that one failing test doesn't abort the whole run.
+fn run_rust_test(test: &RustTestCase, ctx: &TestContext) -> Result<(), ()> {
+    let prev_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| (test.function)(ctx)));
+    panic::set_hook(prev_hook);
+
+    result.map_err(|_| ())
+}
+
+/// Escapes `"` and `\` for embedding `s` inside a JSON string literal.
+fn json_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}